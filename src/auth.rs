@@ -0,0 +1,131 @@
+// Pluggable request authentication for the data/health routers.
+
+use async_trait::async_trait;
+use axum::{
+    extract::{Request, State},
+    http::{header, HeaderMap, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use thiserror::Error;
+
+use crate::state::app_state::AppState;
+
+#[derive(Debug, Clone)]
+pub struct Identity {
+    pub subject: String,
+}
+
+#[derive(Debug, Error)]
+pub enum AuthError {
+    #[error("missing credentials")]
+    MissingCredentials,
+    #[error("invalid token")]
+    InvalidToken,
+}
+
+/// Authenticates an incoming request. `query_token` is passed separately from
+/// `headers` because browsers cannot set arbitrary headers (e.g.
+/// `Authorization`) on a WebSocket upgrade request, so `/fetch/{signal}`
+/// authenticates via a `?token=` query parameter instead.
+#[async_trait]
+pub trait ApiAuth: Send + Sync {
+    async fn authenticate(
+        &self,
+        headers: &HeaderMap,
+        query_token: Option<&str>,
+    ) -> Result<Identity, AuthError>;
+}
+
+/// Default backend: a single shared token read from `ExtensionConfig.api_token`.
+pub struct TokenAuth;
+
+#[async_trait]
+impl ApiAuth for TokenAuth {
+    async fn authenticate(
+        &self,
+        headers: &HeaderMap,
+        query_token: Option<&str>,
+    ) -> Result<Identity, AuthError> {
+        let expected = &crate::utils::conf_helper::get_cached_config().api_token;
+
+        let header_token = headers
+            .get(header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "));
+
+        check_token(expected, header_token.or(query_token))
+    }
+}
+
+/// Core comparison behind [`TokenAuth::authenticate`], split out so it can be
+/// exercised without touching the process-global config cache.
+///
+/// An unset `expected` must never be reachable with "no credentials" - that
+/// would let anyone who can reach the port in, which is exactly what this
+/// trait exists to prevent - so an empty `expected` fails closed. An empty
+/// `supplied` token is likewise treated as missing credentials, not a match.
+fn check_token(expected: &str, supplied: Option<&str>) -> Result<Identity, AuthError> {
+    if expected.is_empty() {
+        return Err(AuthError::MissingCredentials);
+    }
+
+    match supplied.filter(|t| !t.is_empty()) {
+        Some(token) if token == expected => Ok(Identity {
+            subject: "token".to_string(),
+        }),
+        Some(_) => Err(AuthError::InvalidToken),
+        None => Err(AuthError::MissingCredentials),
+    }
+}
+
+fn query_token(req: &Request) -> Option<String> {
+    let query = req.uri().query()?;
+    query.split('&').find_map(|pair| {
+        let (key, value) = pair.split_once('=')?;
+        (key == "token").then(|| value.to_string())
+    })
+}
+
+/// Axum middleware rejecting any request the configured `ApiAuth` impl can't
+/// authenticate with `401 Unauthorized`.
+pub async fn auth_layer(State(state): State<AppState>, req: Request, next: Next) -> Response {
+    let token = query_token(&req);
+
+    match state
+        .auth
+        .authenticate(req.headers(), token.as_deref())
+        .await
+    {
+        Ok(_) => next.run(req).await,
+        Err(e) => {
+            tracing::warn!("rejecting unauthenticated request: {}", e);
+            StatusCode::UNAUTHORIZED.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_configured_token_fails_closed() {
+        assert!(matches!(check_token("", None), Err(AuthError::MissingCredentials)));
+        assert!(matches!(check_token("", Some("")), Err(AuthError::MissingCredentials)));
+        assert!(matches!(check_token("", Some("anything")), Err(AuthError::MissingCredentials)));
+    }
+
+    #[test]
+    fn valid_token_authenticates() {
+        assert!(check_token("secret", Some("secret")).is_ok());
+    }
+
+    #[test]
+    fn wrong_or_missing_token_is_rejected() {
+        assert!(matches!(check_token("secret", Some("wrong")), Err(AuthError::InvalidToken)));
+        assert!(matches!(check_token("secret", None), Err(AuthError::MissingCredentials)));
+        // An empty supplied token is "no credentials", not a match attempt.
+        assert!(matches!(check_token("secret", Some("")), Err(AuthError::MissingCredentials)));
+    }
+}
@@ -0,0 +1,115 @@
+// WebSocket client side of the `/fetch/{signal}` protocol: dials another
+// Plotune reader instance and federates one of its signals into this node's
+// `AppState.signals`, so it can be re-served as if it were a local file.
+
+use futures_util::StreamExt;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+use pltx_reader::{decode_binary_frame, PltxError, Result, SignalBackend, TimeseriesChunk};
+
+// Remote readers only ever federate the single signal they were dialed for,
+// so there's no real signal id to track — any fixed value does.
+const REMOTE_SIGNAL_ID: u32 = 0;
+
+/// A [`SignalBackend`] backed by another Plotune reader instance's
+/// `/fetch/{signal}` WebSocket endpoint rather than a local PLTX file.
+///
+/// `connect` drains the whole stream into memory up front, since
+/// `SignalBackend` exposes a synchronous chunk iterator to
+/// `handle_ws_fetch`; callers that want lazy federation will need an async
+/// variant of that trait.
+pub struct PltxRemoteReader {
+    signal_name: String,
+    chunks: Vec<TimeseriesChunk>,
+}
+
+impl PltxRemoteReader {
+    /// Dials `{base_url}/fetch/{signal_name}?format=binary` and decodes the
+    /// binary frame protocol (see `pltx_reader::decode_binary_frame`) into
+    /// in-memory chunks until the end-flag frame arrives.
+    pub async fn connect(base_url: &str, signal_name: &str) -> Result<Self> {
+        let url = format!(
+            "{}/fetch/{}?format=binary",
+            base_url.trim_end_matches('/'),
+            signal_name
+        );
+
+        let (ws_stream, _) = connect_async(&url)
+            .await
+            .map_err(|e| PltxError::CorruptedData(format!("remote connect to {} failed: {}", url, e)))?;
+
+        let (_, mut read) = ws_stream.split();
+        let mut chunks = Vec::new();
+
+        while let Some(msg) = read.next().await {
+            let msg = msg
+                .map_err(|e| PltxError::CorruptedData(format!("remote ws error: {}", e)))?;
+
+            let bytes = match msg {
+                Message::Binary(bytes) => bytes,
+                Message::Close(_) => break,
+                _ => continue,
+            };
+
+            let (_seq, end_flag, chunk) = decode_binary_frame(&bytes)?;
+            if end_flag {
+                break;
+            }
+            chunks.push(chunk);
+        }
+
+        Ok(Self {
+            signal_name: signal_name.to_string(),
+            chunks,
+        })
+    }
+}
+
+impl SignalBackend for PltxRemoteReader {
+    fn get_signal_id_by_name(&self, name: &str) -> Option<u32> {
+        (name == self.signal_name).then_some(REMOTE_SIGNAL_ID)
+    }
+
+    fn chunk_stream(
+        &self,
+        signal_id: u32,
+    ) -> Result<Box<dyn Iterator<Item = Result<TimeseriesChunk>> + Send>> {
+        if signal_id != REMOTE_SIGNAL_ID {
+            return Err(PltxError::SignalNotFound(signal_id.to_string()));
+        }
+
+        Ok(Box::new(self.chunks.clone().into_iter().map(Ok)))
+    }
+
+    fn read_signal_chunks_in_range(
+        &self,
+        signal_id: u32,
+        from: Option<f64>,
+        to: Option<f64>,
+    ) -> Result<Box<dyn Iterator<Item = Result<TimeseriesChunk>> + Send>> {
+        if signal_id != REMOTE_SIGNAL_ID {
+            return Err(PltxError::SignalNotFound(signal_id.to_string()));
+        }
+
+        let from = from.unwrap_or(f64::NEG_INFINITY);
+        let to = to.unwrap_or(f64::INFINITY);
+
+        let filtered: Vec<TimeseriesChunk> = self
+            .chunks
+            .iter()
+            .map(|chunk| {
+                let mut out = TimeseriesChunk::new();
+                for (ts, val) in chunk.timestamps.iter().zip(chunk.values.iter()) {
+                    if *ts >= from && *ts <= to {
+                        out.timestamps.push(*ts);
+                        out.values.push(*val);
+                    }
+                }
+                out
+            })
+            .filter(|chunk| !chunk.is_empty())
+            .collect();
+
+        Ok(Box::new(filtered.into_iter().map(Ok)))
+    }
+}
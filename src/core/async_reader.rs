@@ -0,0 +1,324 @@
+// Async counterpart to `PltxReader` (see `crate::core::reader`), built on
+// `tokio::io::{AsyncRead, AsyncSeek}` instead of `std::fs::File` so PLTX
+// files coming from sockets or async object storage can be read without
+// blocking an executor thread. Gated behind the `async-reader` Cargo
+// feature since most embedders only ever open local files.
+#![cfg(feature = "async-reader")]
+
+use std::collections::HashMap;
+use std::pin::Pin;
+
+use async_stream::try_stream;
+use futures_core::stream::Stream;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, SeekFrom};
+
+use crate::core::compression::decompress;
+use crate::core::constants::*;
+use crate::core::error::{PltxError, Result};
+use crate::core::format::*;
+
+/// Mirrors the core read API of [`crate::core::reader::PltxReader`]
+/// (`list_signals`, `get_signal_metadata`, `get_signal_id_by_name`,
+/// `read_signal_all`, `read_time_range`, `chunk_stream`), but every read is an
+/// `.await` against `R` rather than a blocking `std::fs::File` call. It has no
+/// counterpart of `read_signal_chunks`, `read_signal_chunks_in_range`,
+/// `read_time_range_chunks` or `chunk_cursor`, and `read_time_range` scans
+/// every index entry of the signal.
+pub struct AsyncPltxReader<R> {
+    reader: R,
+    header: FileHeader,
+    index: HashMap<u32, Vec<IndexEntry>>,
+}
+
+impl<R: AsyncRead + AsyncSeek + Unpin + Send> AsyncPltxReader<R> {
+    pub async fn open(mut reader: R) -> Result<Self> {
+        let header = Self::read_header(&mut reader).await?;
+        let index = Self::read_footer_and_index(&mut reader).await?;
+
+        Ok(Self {
+            reader,
+            header,
+            index,
+        })
+    }
+
+    async fn read_header(reader: &mut R) -> Result<FileHeader> {
+        let mut prefix = [0u8; HEADER_PREFIX_SIZE];
+        reader.read_exact(&mut prefix).await?;
+
+        let magic = &prefix[0..4];
+        if magic != MAGIC {
+            return Err(PltxError::InvalidMagic {
+                expected: MAGIC.to_vec(),
+                got: magic.to_vec(),
+            });
+        }
+
+        let version = prefix[4];
+        let compression = prefix[5];
+        let created = f64::from_le_bytes(prefix[6..14].try_into().unwrap());
+        let sig_count = u16::from_le_bytes(prefix[14..16].try_into().unwrap());
+
+        let mut signals = HashMap::new();
+        for _ in 0..sig_count {
+            let mut sid_buf = [0u8; 4];
+            reader.read_exact(&mut sid_buf).await?;
+            let signal_id = u32::from_le_bytes(sid_buf);
+
+            let name = Self::read_string(reader).await?;
+            let unit = Self::read_string(reader).await?;
+            let description = Self::read_string(reader).await?;
+            let source = Self::read_string(reader).await?;
+
+            signals.insert(
+                signal_id,
+                SignalMetadata {
+                    name,
+                    unit,
+                    description,
+                    source,
+                },
+            );
+        }
+
+        Ok(FileHeader {
+            version,
+            compression,
+            created,
+            signals,
+        })
+    }
+
+    async fn read_string(reader: &mut R) -> Result<String> {
+        let mut len_buf = [0u8; 2];
+        reader.read_exact(&mut len_buf).await?;
+        let len = u16::from_le_bytes(len_buf) as usize;
+
+        let mut str_buf = vec![0u8; len];
+        reader.read_exact(&mut str_buf).await?;
+
+        String::from_utf8(str_buf).map_err(|e| e.into())
+    }
+
+    async fn read_footer_and_index(reader: &mut R) -> Result<HashMap<u32, Vec<IndexEntry>>> {
+        reader.seek(SeekFrom::End(-(FOOTER_SIZE as i64))).await?;
+
+        let mut footer = [0u8; FOOTER_SIZE];
+        reader.read_exact(&mut footer).await?;
+
+        let footer_magic = &footer[0..4];
+        if footer_magic != FOOTER_MAGIC {
+            return Err(PltxError::InvalidMagic {
+                expected: FOOTER_MAGIC.to_vec(),
+                got: footer_magic.to_vec(),
+            });
+        }
+
+        let index_offset = u64::from_le_bytes(footer[4..12].try_into().unwrap());
+        reader.seek(SeekFrom::Start(index_offset)).await?;
+
+        let mut index_magic = [0u8; 4];
+        reader.read_exact(&mut index_magic).await?;
+        if &index_magic != INDEX_MAGIC {
+            return Err(PltxError::InvalidMagic {
+                expected: INDEX_MAGIC.to_vec(),
+                got: index_magic.to_vec(),
+            });
+        }
+
+        let mut count_buf = [0u8; 4];
+        reader.read_exact(&mut count_buf).await?;
+        let entry_count = u32::from_le_bytes(count_buf);
+
+        let mut index: HashMap<u32, Vec<IndexEntry>> = HashMap::new();
+        for _ in 0..entry_count {
+            let mut entry_buf = [0u8; INDEX_ENTRY_SIZE];
+            reader.read_exact(&mut entry_buf).await?;
+
+            let signal_id = u32::from_le_bytes(entry_buf[0..4].try_into().unwrap());
+            let offset = u64::from_le_bytes(entry_buf[4..12].try_into().unwrap());
+            let min_ts = f64::from_le_bytes(entry_buf[12..20].try_into().unwrap());
+            let max_ts = f64::from_le_bytes(entry_buf[20..28].try_into().unwrap());
+
+            index
+                .entry(signal_id)
+                .or_insert_with(Vec::new)
+                .push(IndexEntry {
+                    signal_id,
+                    offset,
+                    min_timestamp: min_ts,
+                    max_timestamp: max_ts,
+                });
+        }
+
+        Ok(index)
+    }
+
+    pub fn list_signals(&self) -> Vec<(u32, &str)> {
+        let mut signals: Vec<_> = self
+            .header
+            .signals
+            .iter()
+            .map(|(id, meta)| (*id, meta.name.as_str()))
+            .collect();
+        signals.sort_by_key(|(id, _)| *id);
+        signals
+    }
+
+    pub fn get_signal_metadata(&self, signal_id: u32) -> Option<&SignalMetadata> {
+        self.header.signals.get(&signal_id)
+    }
+
+    pub fn get_signal_id_by_name(&self, name: &str) -> Option<u32> {
+        self.header
+            .signals
+            .iter()
+            .find(|(_, meta)| meta.name == name)
+            .map(|(id, _)| *id)
+    }
+
+    pub async fn read_signal_all(&mut self, signal_id: u32) -> Result<TimeseriesChunk> {
+        let entries = self
+            .index
+            .get(&signal_id)
+            .ok_or_else(|| PltxError::SignalNotFound(signal_id.to_string()))?
+            .clone();
+
+        let compression = CompressionType::from_u8(self.header.compression)
+            .ok_or(PltxError::UnsupportedCompression(self.header.compression))?;
+
+        let mut result = TimeseriesChunk::new();
+
+        for entry in entries {
+            let chunk = self.read_chunk_at(entry.offset, compression).await?;
+            result.timestamps.extend(chunk.timestamps);
+            result.values.extend(chunk.values);
+        }
+
+        Ok(result)
+    }
+
+    pub async fn read_time_range(
+        &mut self,
+        signal_id: u32,
+        start_time: f64,
+        end_time: f64,
+    ) -> Result<TimeseriesChunk> {
+        let entries = self
+            .index
+            .get(&signal_id)
+            .ok_or_else(|| PltxError::SignalNotFound(signal_id.to_string()))?
+            .clone();
+
+        let compression = CompressionType::from_u8(self.header.compression)
+            .ok_or(PltxError::UnsupportedCompression(self.header.compression))?;
+
+        let mut result = TimeseriesChunk::new();
+
+        for entry in entries {
+            if entry.max_timestamp < start_time || entry.min_timestamp > end_time {
+                continue;
+            }
+
+            let chunk = self.read_chunk_at(entry.offset, compression).await?;
+
+            for (ts, val) in chunk.timestamps.iter().zip(chunk.values.iter()) {
+                if *ts >= start_time && *ts <= end_time {
+                    result.timestamps.push(*ts);
+                    result.values.push(*val);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn read_chunk_at(&mut self, offset: u64, file_compression: CompressionType) -> Result<TimeseriesChunk> {
+        self.reader.seek(SeekFrom::Start(offset)).await?;
+
+        let mut chunk_magic = [0u8; 4];
+        self.reader.read_exact(&mut chunk_magic).await?;
+        if &chunk_magic != CHUNK_MAGIC {
+            return Err(PltxError::CorruptedData("Invalid chunk magic".to_string()));
+        }
+
+        let mut header_buf = [0u8; CHUNK_HEADER_SIZE];
+        self.reader.read_exact(&mut header_buf).await?;
+
+        let record_count = u32::from_le_bytes(header_buf[4..8].try_into().unwrap());
+        let raw_length = u32::from_le_bytes(header_buf[8..12].try_into().unwrap());
+        let compressed_length = u32::from_le_bytes(header_buf[12..16].try_into().unwrap());
+
+        // Files written after per-chunk codecs were introduced carry one
+        // extra codec byte per chunk; older files share the file-level codec.
+        let compression = if self.header.version >= PER_CHUNK_CODEC_VERSION {
+            let mut codec_buf = [0u8; CHUNK_CODEC_SIZE];
+            self.reader.read_exact(&mut codec_buf).await?;
+            CompressionType::from_u8(codec_buf[0])
+                .ok_or(PltxError::UnsupportedCompression(codec_buf[0]))?
+        } else {
+            file_compression
+        };
+
+        let mut compressed_data = vec![0u8; compressed_length as usize];
+        self.reader.read_exact(&mut compressed_data).await?;
+
+        // Decompression is CPU-bound; hand it to the blocking pool so a slow
+        // chunk doesn't stall the executor the way it would inline here.
+        let raw_data = tokio::task::spawn_blocking(move || decompress(&compressed_data, compression))
+            .await
+            .map_err(|e| PltxError::CorruptedData(format!("decompress task panicked: {}", e)))??;
+
+        if raw_data.len() != raw_length as usize {
+            return Err(PltxError::CorruptedData(format!(
+                "Expected {} bytes, got {}",
+                raw_length,
+                raw_data.len()
+            )));
+        }
+        if raw_data.len() != record_count as usize * RECORD_SIZE {
+            return Err(PltxError::CorruptedData(format!(
+                "chunk claims {} records but decompressed to {} bytes",
+                record_count,
+                raw_data.len()
+            )));
+        }
+
+        let mut chunk = TimeseriesChunk::with_capacity(record_count as usize);
+
+        for i in 0..record_count as usize {
+            let offset = i * RECORD_SIZE;
+            let ts = f64::from_le_bytes(raw_data[offset..offset + 8].try_into().unwrap());
+            let val = f64::from_le_bytes(raw_data[offset + 8..offset + 16].try_into().unwrap());
+            chunk.timestamps.push(ts);
+            chunk.values.push(val);
+        }
+
+        Ok(chunk)
+    }
+
+    /// Async counterpart to [`crate::core::reader::PltxReader::chunk_stream`]:
+    /// yields one decoded [`TimeseriesChunk`] per item, seeking to each
+    /// `IndexEntry.offset` and awaiting its decompression off-thread instead
+    /// of materializing the whole signal up front.
+    pub fn chunk_stream(
+        &mut self,
+        signal_id: u32,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<TimeseriesChunk>> + Send + '_>>> {
+        let entries = self
+            .index
+            .get(&signal_id)
+            .ok_or_else(|| PltxError::SignalNotFound(signal_id.to_string()))?
+            .clone();
+
+        let compression = CompressionType::from_u8(self.header.compression)
+            .ok_or(PltxError::UnsupportedCompression(self.header.compression))?;
+
+        Ok(Box::pin(try_stream! {
+            for entry in entries {
+                let chunk = self.read_chunk_at(entry.offset, compression).await?;
+                yield chunk;
+            }
+        }))
+    }
+}
@@ -0,0 +1,49 @@
+// Generic signal backend abstraction: lets `handle_ws_fetch` stream points
+// from either a local `PltxReader` or a federated remote reader uniformly.
+
+use crate::core::error::Result;
+use crate::core::format::TimeseriesChunk;
+use crate::core::reader::PltxReader;
+
+/// A source of chunked signal data that the WebSocket fetch handler can
+/// stream from, regardless of whether it is backed by a local PLTX file or
+/// a remote Plotune reader instance.
+pub trait SignalBackend: Send + Sync {
+    fn get_signal_id_by_name(&self, name: &str) -> Option<u32>;
+
+    fn chunk_stream(
+        &self,
+        signal_id: u32,
+    ) -> Result<Box<dyn Iterator<Item = Result<TimeseriesChunk>> + Send>>;
+
+    fn read_signal_chunks_in_range(
+        &self,
+        signal_id: u32,
+        from: Option<f64>,
+        to: Option<f64>,
+    ) -> Result<Box<dyn Iterator<Item = Result<TimeseriesChunk>> + Send>>;
+}
+
+impl SignalBackend for PltxReader {
+    fn get_signal_id_by_name(&self, name: &str) -> Option<u32> {
+        PltxReader::get_signal_id_by_name(self, name)
+    }
+
+    fn chunk_stream(
+        &self,
+        signal_id: u32,
+    ) -> Result<Box<dyn Iterator<Item = Result<TimeseriesChunk>> + Send>> {
+        Ok(Box::new(PltxReader::chunk_stream(self, signal_id)?))
+    }
+
+    fn read_signal_chunks_in_range(
+        &self,
+        signal_id: u32,
+        from: Option<f64>,
+        to: Option<f64>,
+    ) -> Result<Box<dyn Iterator<Item = Result<TimeseriesChunk>> + Send>> {
+        Ok(Box::new(PltxReader::read_signal_chunks_in_range(
+            self, signal_id, from, to,
+        )?))
+    }
+}
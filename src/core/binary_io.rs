@@ -0,0 +1,301 @@
+// Trait-based binary (de)serialization, replacing the hand-rolled
+// `from_le_bytes`/`try_into().unwrap()` call sites that used to be scattered
+// across `PltxReader::read_header`, `read_footer_and_index`, and
+// `read_chunk_at`. Centralizes magic validation and turns malformed input
+// into `PltxError::CorruptedData` instead of a panic.
+
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use crate::core::constants::*;
+use crate::core::error::{PltxError, Result};
+use crate::core::format::*;
+
+/// Decodes a value from a byte-oriented reader.
+pub trait FromReader: Sized {
+    fn from_reader<R: Read + Seek>(r: &mut R) -> Result<Self>;
+}
+
+/// Inverse of [`FromReader`]: encodes a value to a byte-oriented writer.
+/// A prerequisite for `PltxWriter`.
+pub trait ToWriter {
+    fn to_writer<W: Write>(&self, w: &mut W) -> Result<()>;
+}
+
+/// Reports a short read as `PltxError::CorruptedData` (truncated input),
+/// passing every other I/O error through unchanged.
+fn eof_as_corrupted(e: std::io::Error) -> PltxError {
+    if e.kind() == std::io::ErrorKind::UnexpectedEof {
+        PltxError::CorruptedData(format!("unexpected end of data: {}", e))
+    } else {
+        e.into()
+    }
+}
+
+fn read_array<R: Read, const N: usize>(r: &mut R) -> Result<[u8; N]> {
+    let mut buf = [0u8; N];
+    r.read_exact(&mut buf).map_err(eof_as_corrupted)?;
+    Ok(buf)
+}
+
+fn read_u16<R: Read>(r: &mut R) -> Result<u16> {
+    Ok(u16::from_le_bytes(read_array(r)?))
+}
+
+fn read_u32<R: Read>(r: &mut R) -> Result<u32> {
+    Ok(u32::from_le_bytes(read_array(r)?))
+}
+
+fn read_u64<R: Read>(r: &mut R) -> Result<u64> {
+    Ok(u64::from_le_bytes(read_array(r)?))
+}
+
+fn read_f64<R: Read>(r: &mut R) -> Result<f64> {
+    Ok(f64::from_le_bytes(read_array(r)?))
+}
+
+fn read_string<R: Read>(r: &mut R) -> Result<String> {
+    let len = read_u16(r)? as usize;
+    let mut buf = vec![0u8; len];
+    r.read_exact(&mut buf).map_err(eof_as_corrupted)?;
+    String::from_utf8(buf).map_err(|e| e.into())
+}
+
+fn write_string<W: Write>(w: &mut W, s: &str) -> Result<()> {
+    let bytes = s.as_bytes();
+    if bytes.len() > u16::MAX as usize {
+        return Err(PltxError::CorruptedData(format!(
+            "string too long to encode: {} bytes",
+            bytes.len()
+        )));
+    }
+    w.write_all(&(bytes.len() as u16).to_le_bytes())?;
+    w.write_all(bytes)?;
+    Ok(())
+}
+
+/// Reads `expected.len()` bytes and checks them against `expected`,
+/// returning `PltxError::InvalidMagic` on mismatch instead of leaving the
+/// check to ad-hoc slice comparisons at each call site.
+fn expect_magic<R: Read>(r: &mut R, expected: &[u8; 4]) -> Result<()> {
+    let got: [u8; 4] = read_array(r)?;
+    if &got != expected {
+        return Err(PltxError::InvalidMagic {
+            expected: expected.to_vec(),
+            got: got.to_vec(),
+        });
+    }
+    Ok(())
+}
+
+impl FromReader for SignalMetadata {
+    fn from_reader<R: Read + Seek>(r: &mut R) -> Result<Self> {
+        Ok(SignalMetadata {
+            name: read_string(r)?,
+            unit: read_string(r)?,
+            description: read_string(r)?,
+            source: read_string(r)?,
+        })
+    }
+}
+
+impl ToWriter for SignalMetadata {
+    fn to_writer<W: Write>(&self, w: &mut W) -> Result<()> {
+        write_string(w, &self.name)?;
+        write_string(w, &self.unit)?;
+        write_string(w, &self.description)?;
+        write_string(w, &self.source)?;
+        Ok(())
+    }
+}
+
+impl FromReader for IndexEntry {
+    fn from_reader<R: Read + Seek>(r: &mut R) -> Result<Self> {
+        Ok(IndexEntry {
+            signal_id: read_u32(r)?,
+            offset: read_u64(r)?,
+            min_timestamp: read_f64(r)?,
+            max_timestamp: read_f64(r)?,
+        })
+    }
+}
+
+impl ToWriter for IndexEntry {
+    fn to_writer<W: Write>(&self, w: &mut W) -> Result<()> {
+        w.write_all(&self.signal_id.to_le_bytes())?;
+        w.write_all(&self.offset.to_le_bytes())?;
+        w.write_all(&self.min_timestamp.to_le_bytes())?;
+        w.write_all(&self.max_timestamp.to_le_bytes())?;
+        Ok(())
+    }
+}
+
+impl FromReader for FileHeader {
+    fn from_reader<R: Read + Seek>(r: &mut R) -> Result<Self> {
+        expect_magic(r, MAGIC)?;
+
+        let version = read_array::<R, 1>(r)?[0];
+        let compression = read_array::<R, 1>(r)?[0];
+        let created = read_f64(r)?;
+        let sig_count = read_u16(r)?;
+
+        let mut signals = std::collections::HashMap::new();
+        for _ in 0..sig_count {
+            let signal_id = read_u32(r)?;
+            let metadata = SignalMetadata::from_reader(r)?;
+            signals.insert(signal_id, metadata);
+        }
+
+        Ok(FileHeader {
+            version,
+            compression,
+            created,
+            signals,
+        })
+    }
+}
+
+impl ToWriter for FileHeader {
+    fn to_writer<W: Write>(&self, w: &mut W) -> Result<()> {
+        w.write_all(MAGIC)?;
+        w.write_all(&[self.version, self.compression])?;
+        w.write_all(&self.created.to_le_bytes())?;
+        w.write_all(&(self.signals.len() as u16).to_le_bytes())?;
+
+        for (signal_id, metadata) in &self.signals {
+            w.write_all(&signal_id.to_le_bytes())?;
+            metadata.to_writer(w)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// On-disk `IDXT` index section: `FOOTER`-referenced table of every
+/// signal's chunk offsets, decoded in one pass by `PltxReader::open`.
+pub struct IndexSection {
+    pub entries: Vec<IndexEntry>,
+}
+
+impl FromReader for IndexSection {
+    fn from_reader<R: Read + Seek>(r: &mut R) -> Result<Self> {
+        expect_magic(r, INDEX_MAGIC)?;
+
+        let entry_count = read_u32(r)?;
+        let mut entries = Vec::with_capacity(entry_count as usize);
+        for _ in 0..entry_count {
+            entries.push(IndexEntry::from_reader(r)?);
+        }
+
+        Ok(IndexSection { entries })
+    }
+}
+
+impl ToWriter for IndexSection {
+    fn to_writer<W: Write>(&self, w: &mut W) -> Result<()> {
+        w.write_all(INDEX_MAGIC)?;
+        w.write_all(&(self.entries.len() as u32).to_le_bytes())?;
+        for entry in &self.entries {
+            entry.to_writer(w)?;
+        }
+        Ok(())
+    }
+}
+
+/// On-disk `FTER` footer: just a pointer to where the index section starts.
+pub struct Footer {
+    pub index_offset: u64,
+}
+
+impl FromReader for Footer {
+    fn from_reader<R: Read + Seek>(r: &mut R) -> Result<Self> {
+        r.seek(SeekFrom::End(-(FOOTER_SIZE as i64)))?;
+        expect_magic(r, FOOTER_MAGIC)?;
+        let index_offset = read_u64(r)?;
+        Ok(Footer { index_offset })
+    }
+}
+
+impl ToWriter for Footer {
+    fn to_writer<W: Write>(&self, w: &mut W) -> Result<()> {
+        w.write_all(FOOTER_MAGIC)?;
+        w.write_all(&self.index_offset.to_le_bytes())?;
+        Ok(())
+    }
+}
+
+impl FromReader for ChunkHeader {
+    fn from_reader<R: Read + Seek>(r: &mut R) -> Result<Self> {
+        expect_magic(r, CHUNK_MAGIC)?;
+
+        let signal_id = read_u32(r)?;
+        let record_count = read_u32(r)?;
+        let raw_length = read_u32(r)?;
+        let compressed_length = read_u32(r)?;
+        let min_timestamp = read_f64(r)?;
+        let max_timestamp = read_f64(r)?;
+
+        Ok(ChunkHeader {
+            signal_id,
+            record_count,
+            raw_length,
+            compressed_length,
+            min_timestamp,
+            max_timestamp,
+            // Per-chunk codec (added in the `async-reader`-era format
+            // revision) isn't part of this fixed-size prefix: it's only
+            // present for `FileHeader.version >= PER_CHUNK_CODEC_VERSION`,
+            // so callers read it themselves right after this header.
+            codec: 0,
+        })
+    }
+}
+
+impl ToWriter for ChunkHeader {
+    fn to_writer<W: Write>(&self, w: &mut W) -> Result<()> {
+        w.write_all(CHUNK_MAGIC)?;
+        w.write_all(&self.signal_id.to_le_bytes())?;
+        w.write_all(&self.record_count.to_le_bytes())?;
+        w.write_all(&self.raw_length.to_le_bytes())?;
+        w.write_all(&self.compressed_length.to_le_bytes())?;
+        w.write_all(&self.min_timestamp.to_le_bytes())?;
+        w.write_all(&self.max_timestamp.to_le_bytes())?;
+        Ok(())
+    }
+}
+
+impl FromReader for TimeseriesChunk {
+    /// Decodes `(timestamp, value)` pairs until EOF. Expects `r` to be
+    /// bounded to exactly the decompressed record bytes of one chunk (e.g. a
+    /// `Cursor` over `raw_data`), since nothing in the record layout itself
+    /// says how many pairs follow.
+    fn from_reader<R: Read + Seek>(r: &mut R) -> Result<Self> {
+        let mut chunk = TimeseriesChunk::new();
+
+        loop {
+            let mut ts_buf = [0u8; 8];
+            match r.read_exact(&mut ts_buf) {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => break,
+                Err(e) => return Err(e.into()),
+            }
+
+            let ts = f64::from_le_bytes(ts_buf);
+            let val = read_f64(r)?;
+
+            chunk.timestamps.push(ts);
+            chunk.values.push(val);
+        }
+
+        Ok(chunk)
+    }
+}
+
+impl ToWriter for TimeseriesChunk {
+    fn to_writer<W: Write>(&self, w: &mut W) -> Result<()> {
+        for (ts, val) in self.timestamps.iter().zip(self.values.iter()) {
+            w.write_all(&ts.to_le_bytes())?;
+            w.write_all(&val.to_le_bytes())?;
+        }
+        Ok(())
+    }
+}
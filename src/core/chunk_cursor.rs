@@ -0,0 +1,207 @@
+// Bounded, record-level random access into a single decompressed chunk, for
+// callers that want one record (or a handful) instead of paying to decode
+// the whole chunk into a `TimeseriesChunk`'s two parallel `Vec<f64>`s.
+
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::sync::{Arc, Mutex};
+
+use crate::core::binary_io::FromReader;
+use crate::core::compression::decompress;
+use crate::core::constants::*;
+use crate::core::error::{PltxError, Result};
+use crate::core::format::ChunkHeader;
+
+/// Seekable view over one chunk's decompressed records.
+///
+/// The decompressed bytes are read once, up front. The read is bounded by
+/// `ChunkHeader.compressed_length` itself: a length that runs past the end of
+/// the file is rejected as `PltxError::CorruptedData`, but one that runs into
+/// a following chunk is not detected as such and is only rejected if those
+/// bytes then fail decompression or the `raw_length`/`record_count` checks.
+/// From there, records are
+/// decoded on demand by indexing `i * RECORD_SIZE` into the buffer instead of
+/// all being parsed into `TimeseriesChunk` up front.
+pub struct ChunkCursor {
+    record_count: u32,
+    raw: Vec<u8>,
+}
+
+impl ChunkCursor {
+    pub(crate) fn open(
+        file: &Arc<Mutex<File>>,
+        offset: u64,
+        file_compression: CompressionType,
+        version: u8,
+    ) -> Result<Self> {
+        let mut file = file.lock().unwrap();
+        file.seek(SeekFrom::Start(offset))?;
+
+        let header = ChunkHeader::from_reader(&mut *file)?;
+
+        let compression = if version >= PER_CHUNK_CODEC_VERSION {
+            let mut codec_buf = [0u8; CHUNK_CODEC_SIZE];
+            file.read_exact(&mut codec_buf)?;
+            CompressionType::from_u8(codec_buf[0])
+                .ok_or(PltxError::UnsupportedCompression(codec_buf[0]))?
+        } else {
+            file_compression
+        };
+
+        // `.take(compressed_length)` bounds the read so a `compressed_length`
+        // that runs past the end of the file under-reads (caught below)
+        // instead of failing inside `read_exact`. The chunk's end on disk is
+        // not known here, so a length reaching into the next chunk is read.
+        let mut bounded = (&mut *file).take(header.compressed_length as u64);
+        let mut compressed_data = Vec::with_capacity(header.compressed_length as usize);
+        bounded.read_to_end(&mut compressed_data)?;
+        if compressed_data.len() != header.compressed_length as usize {
+            return Err(PltxError::CorruptedData(format!(
+                "chunk at offset {offset} claims {} compressed bytes but only {} were readable",
+                header.compressed_length,
+                compressed_data.len()
+            )));
+        }
+
+        let raw = decompress(&compressed_data, compression)?;
+        if raw.len() != header.raw_length as usize {
+            return Err(PltxError::CorruptedData(format!(
+                "chunk at offset {offset} expected {} raw bytes, got {}",
+                header.raw_length,
+                raw.len()
+            )));
+        }
+        if raw.len() != header.record_count as usize * RECORD_SIZE {
+            return Err(PltxError::CorruptedData(format!(
+                "chunk at offset {offset} claims {} records but decompressed to {} bytes",
+                header.record_count,
+                raw.len()
+            )));
+        }
+
+        Ok(Self {
+            record_count: header.record_count,
+            raw,
+        })
+    }
+
+    pub fn record_count(&self) -> u32 {
+        self.record_count
+    }
+
+    /// Decodes record `i` as `(timestamp, value)` without decoding any other
+    /// record. Out-of-bounds `i` is a `PltxError::CorruptedData` rather than
+    /// a panic, since `record_count` itself comes from on-disk data.
+    pub fn record(&self, i: u32) -> Result<(f64, f64)> {
+        if i >= self.record_count {
+            return Err(PltxError::CorruptedData(format!(
+                "record {i} out of bounds ({} records in chunk)",
+                self.record_count
+            )));
+        }
+
+        let start = i as usize * RECORD_SIZE;
+        let ts = f64::from_le_bytes(self.raw[start..start + 8].try_into().unwrap());
+        let val = f64::from_le_bytes(self.raw[start + 8..start + RECORD_SIZE].try_into().unwrap());
+        Ok((ts, val))
+    }
+
+    /// Iterates every record in order as `(timestamp, value)` pairs, decoding
+    /// each lazily rather than materializing two full `Vec<f64>`s.
+    pub fn iter(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        (0..self.record_count).map(move |i| self.record(i).expect("i is in 0..record_count"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::binary_io::ToWriter;
+    use crate::core::format::ChunkHeader;
+    use std::io::Write;
+
+    fn write_chunk(path: &std::path::Path, header: &ChunkHeader, compressed: &[u8]) {
+        let mut file = File::create(path).unwrap();
+        header.to_writer(&mut file).unwrap();
+        file.write_all(compressed).unwrap();
+    }
+
+    fn open_cursor(path: &std::path::Path) -> Result<ChunkCursor> {
+        let file = Arc::new(Mutex::new(File::open(path).unwrap()));
+        ChunkCursor::open(&file, 0, CompressionType::None, 1)
+    }
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("pltx_chunk_cursor_test_{}_{}.chnk", std::process::id(), name))
+    }
+
+    #[test]
+    fn reads_records_in_bounds_and_rejects_out_of_bounds() {
+        let path = temp_path("in_bounds");
+        let raw: Vec<u8> = [1.0f64, 10.0, 2.0, 20.0]
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .collect();
+        let header = ChunkHeader {
+            signal_id: 1,
+            record_count: 2,
+            raw_length: raw.len() as u32,
+            compressed_length: raw.len() as u32,
+            min_timestamp: 1.0,
+            max_timestamp: 2.0,
+            codec: 0,
+        };
+        write_chunk(&path, &header, &raw);
+
+        let cursor = open_cursor(&path).unwrap();
+        assert_eq!(cursor.record_count(), 2);
+        assert_eq!(cursor.record(0).unwrap(), (1.0, 10.0));
+        assert_eq!(cursor.record(1).unwrap(), (2.0, 20.0));
+        assert!(cursor.record(2).is_err());
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn rejects_compressed_length_that_overruns_the_file() {
+        let path = temp_path("overrun_compressed");
+        let raw = vec![0u8; 16];
+        let header = ChunkHeader {
+            signal_id: 1,
+            record_count: 1,
+            raw_length: 16,
+            // Claims far more compressed bytes than actually follow.
+            compressed_length: 1_000_000,
+            min_timestamp: 0.0,
+            max_timestamp: 0.0,
+            codec: 0,
+        };
+        write_chunk(&path, &header, &raw);
+
+        let err = open_cursor(&path).unwrap_err();
+        assert!(matches!(err, PltxError::CorruptedData(_)));
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn rejects_record_count_inflated_beyond_the_decompressed_length() {
+        let path = temp_path("inflated_record_count");
+        let raw = vec![0u8; 16]; // one record's worth of bytes
+        let header = ChunkHeader {
+            signal_id: 1,
+            record_count: 1_000_000, // wildly more than `raw` can hold
+            raw_length: raw.len() as u32,
+            compressed_length: raw.len() as u32,
+            min_timestamp: 0.0,
+            max_timestamp: 0.0,
+            codec: 0,
+        };
+        write_chunk(&path, &header, &raw);
+
+        let err = open_cursor(&path).unwrap_err();
+        assert!(matches!(err, PltxError::CorruptedData(_)));
+
+        std::fs::remove_file(&path).ok();
+    }
+}
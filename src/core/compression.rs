@@ -39,6 +39,81 @@ pub fn decompress(data: &[u8], compression: CompressionType) -> Result<Vec<u8>>
         CompressionType::Zstd => {
             Err(PltxError::UnsupportedCompression(3))
         }
+
+        #[cfg(feature = "lz4")]
+        CompressionType::Lz4Hc => {
+            // The LZ4 block format doesn't encode the compression level used,
+            // so high-compression blocks decode with the same routine as
+            // regular LZ4 ones.
+            lz4::block::decompress(data, None)
+                .map_err(|e| PltxError::DecompressionFailed(format!("LZ4 HC: {}", e)))
+        }
+
+        #[cfg(not(feature = "lz4"))]
+        CompressionType::Lz4Hc => {
+            Err(PltxError::UnsupportedCompression(4))
+        }
+    }
+}
+
+/// Compresses `data` with the given codec. The inverse of [`decompress`];
+/// used by `PltxWriter` when flushing a chunk.
+pub fn compress(data: &[u8], compression: CompressionType) -> Result<Vec<u8>> {
+    match compression {
+        CompressionType::None => Ok(data.to_vec()),
+
+        CompressionType::Zlib => {
+            use flate2::write::ZlibEncoder;
+            use flate2::Compression;
+            use std::io::Write;
+
+            let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+            encoder
+                .write_all(data)
+                .map_err(|e| PltxError::CompressionFailed(format!("Zlib: {}", e)))?;
+            encoder
+                .finish()
+                .map_err(|e| PltxError::CompressionFailed(format!("Zlib: {}", e)))
+        }
+
+        #[cfg(feature = "lz4")]
+        CompressionType::Lz4 => {
+            lz4::block::compress(data, Some(lz4::block::CompressionMode::DEFAULT), true)
+                .map_err(|e| PltxError::CompressionFailed(format!("LZ4: {}", e)))
+        }
+
+        #[cfg(not(feature = "lz4"))]
+        CompressionType::Lz4 => {
+            Err(PltxError::UnsupportedCompression(2))
+        }
+
+        #[cfg(feature = "zstd")]
+        CompressionType::Zstd => {
+            zstd::encode_all(data, 0)
+                .map_err(|e| PltxError::CompressionFailed(format!("Zstd: {}", e)))
+        }
+
+        #[cfg(not(feature = "zstd"))]
+        CompressionType::Zstd => {
+            Err(PltxError::UnsupportedCompression(3))
+        }
+
+        #[cfg(feature = "lz4")]
+        CompressionType::Lz4Hc => {
+            // Pure-Rust LZ4 can't express the "high compression" search
+            // effort; the C-backed `lz4` crate's block encoder can.
+            lz4::block::compress(
+                data,
+                Some(lz4::block::CompressionMode::HIGHCOMPRESSION(9)),
+                true,
+            )
+            .map_err(|e| PltxError::CompressionFailed(format!("LZ4 HC: {}", e)))
+        }
+
+        #[cfg(not(feature = "lz4"))]
+        CompressionType::Lz4Hc => {
+            Err(PltxError::UnsupportedCompression(4))
+        }
     }
 }
 
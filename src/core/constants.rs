@@ -13,6 +13,11 @@ pub enum CompressionType {
     Zlib = 1,
     Lz4 = 2,
     Zstd = 3,
+    /// LZ4 encoded with the "high compression" level. Decodes identically to
+    /// `Lz4` (the LZ4 block format doesn't vary with compression effort);
+    /// kept as its own id so a chunk's header records which level a writer
+    /// actually chose.
+    Lz4Hc = 4,
 }
 
 impl CompressionType {
@@ -22,6 +27,7 @@ impl CompressionType {
             1 => Some(CompressionType::Zlib),
             2 => Some(CompressionType::Lz4),
             3 => Some(CompressionType::Zstd),
+            4 => Some(CompressionType::Lz4Hc),
             _ => None,
         }
     }
@@ -33,6 +39,20 @@ pub const RECORD_SIZE: usize = 16; // 8 + 8 bytes
 // Chunk header: signal_id(u32) n(u32) raw_len(u32) comp_len(u32) min_ts(f64) max_ts(f64)
 pub const CHUNK_HEADER_SIZE: usize = 4 + 4 + 4 + 4 + 8 + 8; // 32 bytes
 
+// Starting with this `FileHeader.version`, each chunk header is followed by
+// one extra codec(u8) byte (see `CHUNK_CODEC_SIZE`) naming the
+// `CompressionType` used for that chunk specifically, instead of every chunk
+// in the file sharing the single file-level `FileHeader.compression` byte.
+// `2` ("PLTX v2") is the original on-disk format every existing file was
+// written as, with no codec byte - it must stay below this gate so those
+// files take the file-level-codec fallback instead of having a spurious
+// byte read out of their chunk stream.
+pub const PER_CHUNK_CODEC_VERSION: u8 = 3;
+pub const CHUNK_CODEC_SIZE: usize = 1;
+
+// Format version `PltxWriter` emits.
+pub const CURRENT_VERSION: u8 = PER_CHUNK_CODEC_VERSION;
+
 // File header prefix: MAGIC(4) version(u8) comp(u8) created(f64) sig_count(u16)
 pub const HEADER_PREFIX_SIZE: usize = 4 + 1 + 1 + 8 + 2; // 16 bytes
 
@@ -3,7 +3,9 @@ use serde::Serialize;
 use std::sync::Arc;
 use tracing::{info, warn, error};
 
-use crate::core::reader::PltxReader;
+use crate::core::backend::SignalBackend;
+use crate::core::error::{PltxError, Result};
+use crate::core::format::TimeseriesChunk;
 
 #[derive(Serialize)]
 struct SignalPayload {
@@ -14,17 +16,101 @@ struct SignalPayload {
     end_flag: bool,
 }
 
+/// Optional `[from, to]` timestamp window for a fetch request; see
+/// `PltxReader::read_signal_chunks_in_range`. Either bound may be omitted.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FetchRange {
+    pub from: Option<f64>,
+    pub to: Option<f64>,
+}
+
+impl FetchRange {
+    fn is_unbounded(&self) -> bool {
+        self.from.is_none() && self.to.is_none()
+    }
+}
+
+/// Wire protocol used to stream points over the `/fetch/{signal}` socket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FetchProtocol {
+    /// One JSON text frame per point. Default, kept for compatibility.
+    #[default]
+    Json,
+    /// One binary frame per chunk: a small header followed by packed
+    /// little-endian `(timestamp, value)` pairs. See `encode_binary_frame`.
+    Binary,
+}
+
+// Binary frame layout: chunk_seq(u64 LE) point_count(u32 LE) end_flag(u8)
+// followed by `point_count` little-endian (f64 timestamp, f64 value) pairs.
+const BINARY_FRAME_HEADER_SIZE: usize = 8 + 4 + 1;
+
+fn encode_binary_frame(chunk_seq: u64, chunk: Option<&TimeseriesChunk>, end_flag: bool) -> Vec<u8> {
+    let point_count = chunk.map(TimeseriesChunk::len).unwrap_or(0);
+    let mut frame = Vec::with_capacity(BINARY_FRAME_HEADER_SIZE + point_count * crate::core::constants::RECORD_SIZE);
+
+    frame.extend_from_slice(&chunk_seq.to_le_bytes());
+    frame.extend_from_slice(&(point_count as u32).to_le_bytes());
+    frame.push(end_flag as u8);
+
+    if let Some(chunk) = chunk {
+        for (ts, val) in chunk.timestamps.iter().zip(chunk.values.iter()) {
+            frame.extend_from_slice(&ts.to_le_bytes());
+            frame.extend_from_slice(&val.to_le_bytes());
+        }
+    }
+
+    frame
+}
+
+/// Decodes a frame produced by `encode_binary_frame`, returning the chunk
+/// sequence number, whether it's the terminal end-flag frame, and the
+/// decoded points (empty for the end-flag frame). Used by
+/// `client::remote_reader::PltxRemoteReader` to replay a federated stream.
+pub fn decode_binary_frame(bytes: &[u8]) -> Result<(u64, bool, TimeseriesChunk)> {
+    if bytes.len() < BINARY_FRAME_HEADER_SIZE {
+        return Err(PltxError::CorruptedData("binary frame too short".to_string()));
+    }
+
+    let chunk_seq = u64::from_le_bytes(bytes[0..8].try_into().unwrap());
+    let point_count = u32::from_le_bytes(bytes[8..12].try_into().unwrap()) as usize;
+    let end_flag = bytes[12] != 0;
+
+    let expected_len = BINARY_FRAME_HEADER_SIZE + point_count * crate::core::constants::RECORD_SIZE;
+    if bytes.len() != expected_len {
+        return Err(PltxError::CorruptedData(format!(
+            "binary frame length mismatch: expected {}, got {}",
+            expected_len,
+            bytes.len()
+        )));
+    }
+
+    let mut chunk = TimeseriesChunk::with_capacity(point_count);
+    for i in 0..point_count {
+        let offset = BINARY_FRAME_HEADER_SIZE + i * crate::core::constants::RECORD_SIZE;
+        let ts = f64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap());
+        let val = f64::from_le_bytes(bytes[offset + 8..offset + 16].try_into().unwrap());
+        chunk.timestamps.push(ts);
+        chunk.values.push(val);
+    }
+
+    Ok((chunk_seq, end_flag, chunk))
+}
+
 pub async fn handle_ws_fetch(
     mut socket: WebSocket,
-    reader: Arc<tokio::sync::Mutex<PltxReader>>,
+    reader: Arc<tokio::sync::Mutex<dyn SignalBackend>>,
     signal_name: String,
+    range: FetchRange,
+    protocol: FetchProtocol,
+    downsample_points: Option<usize>,
 ) {
     info!("ws_fetch streaming started: {}", signal_name);
 
     let mut seq: u64 = 0;
 
-    // 🔒 Lock the reader briefly to get signal ID and read chunks
-    let chunks = {
+    // 🔒 Lock the reader briefly to resolve the signal ID and build the chunk stream
+    let chunk_stream: Box<dyn Iterator<Item = Result<TimeseriesChunk>> + Send> = {
         let reader_guard = reader.lock().await;
 
         // Get signal ID
@@ -36,57 +122,119 @@ pub async fn handle_ws_fetch(
             }
         };
 
-        // Read all chunks at once
-        match reader_guard.read_signal_chunks(signal_id) {
-            Ok(chunks) => chunks,
-            Err(e) => {
-                error!("read_signal_chunks failed: {}", e);
-                return;
+        if range.is_unbounded() {
+            match reader_guard.chunk_stream(signal_id) {
+                Ok(stream) => Box::new(stream),
+                Err(e) => {
+                    error!("chunk_stream failed: {}", e);
+                    return;
+                }
+            }
+        } else {
+            match reader_guard.read_signal_chunks_in_range(signal_id, range.from, range.to) {
+                Ok(stream) => Box::new(stream),
+                Err(e) => {
+                    error!("read_signal_chunks_in_range failed: {}", e);
+                    return;
+                }
             }
         }
     }; // Lock is released here
 
-    // Iterate through chunks
-    for chunk in chunks {
-
-        // Send each point in the chunk
-        for i in 0..chunk.len() {
-            let payload = SignalPayload {
-                timestamp: chunk.timestamps[i],
-                value: chunk.values[i],
-                desc: String::new(),
-                seq,
-                end_flag: false,
-            };
-
-            let json = match serde_json::to_string(&payload) {
-                Ok(j) => j,
-                Err(e) => {
-                    error!("json serialize error: {}", e);
-                    return;
+    // LTTB needs the whole signal in memory to pick representative points,
+    // so a requested `points` count forgoes the lazy per-chunk iteration
+    // above and merges everything into a single decimated chunk first.
+    let chunk_stream: Box<dyn Iterator<Item = Result<TimeseriesChunk>> + Send> = match downsample_points
+    {
+        Some(points) => {
+            let mut merged = TimeseriesChunk::new();
+            for chunk_result in chunk_stream {
+                match chunk_result {
+                    Ok(chunk) => {
+                        merged.timestamps.extend(chunk.timestamps);
+                        merged.values.extend(chunk.values);
+                    }
+                    Err(e) => {
+                        error!("chunk decode failed: {}", e);
+                        return;
+                    }
                 }
-            };
+            }
+
+            let decimated = crate::core::downsample::lttb(&merged, points);
+            Box::new(std::iter::once(Ok(decimated)))
+        }
+        None => chunk_stream,
+    };
 
-            if let Err(e) = socket.send(Message::Text(json.into())).await {
-                warn!("ws send failed: {}", e);
+    // Iterate through chunks, decompressing (and briefly re-locking the reader) one at a time
+    for chunk_result in chunk_stream {
+        let chunk = match chunk_result {
+            Ok(chunk) => chunk,
+            Err(e) => {
+                error!("chunk decode failed: {}", e);
                 return;
             }
+        };
 
-            seq += 1;
+        match protocol {
+            FetchProtocol::Json => {
+                // Send each point in the chunk
+                for i in 0..chunk.len() {
+                    let payload = SignalPayload {
+                        timestamp: chunk.timestamps[i],
+                        value: chunk.values[i],
+                        desc: String::new(),
+                        seq,
+                        end_flag: false,
+                    };
+
+                    let json = match serde_json::to_string(&payload) {
+                        Ok(j) => j,
+                        Err(e) => {
+                            error!("json serialize error: {}", e);
+                            return;
+                        }
+                    };
+
+                    if let Err(e) = socket.send(Message::Text(json.into())).await {
+                        warn!("ws send failed: {}", e);
+                        return;
+                    }
+
+                    seq += 1;
+                }
+            }
+            FetchProtocol::Binary => {
+                let frame = encode_binary_frame(seq, Some(&chunk), false);
+                if let Err(e) = socket.send(Message::Binary(frame.into())).await {
+                    warn!("ws send failed: {}", e);
+                    return;
+                }
+                seq += 1;
+            }
         }
     }
 
     // 🔚 END FLAG
-    let end_payload = SignalPayload {
-        timestamp: 0.0,
-        value: 0.0,
-        desc: String::new(),
-        seq,
-        end_flag: true,
-    };
+    match protocol {
+        FetchProtocol::Json => {
+            let end_payload = SignalPayload {
+                timestamp: 0.0,
+                value: 0.0,
+                desc: String::new(),
+                seq,
+                end_flag: true,
+            };
 
-    if let Ok(json) = serde_json::to_string(&end_payload) {
-        let _ = socket.send(Message::Text(json.into())).await;
+            if let Ok(json) = serde_json::to_string(&end_payload) {
+                let _ = socket.send(Message::Text(json.into())).await;
+            }
+        }
+        FetchProtocol::Binary => {
+            let frame = encode_binary_frame(seq, None, true);
+            let _ = socket.send(Message::Binary(frame.into())).await;
+        }
     }
 
     info!("ws_fetch finished: {}", signal_name);
@@ -0,0 +1,124 @@
+// Largest-Triangle-Three-Buckets (LTTB) decimation for plot-oriented
+// streaming: reduces a signal to a handful of visually representative
+// points without the peak/trough loss naive stride sampling produces.
+
+use crate::core::format::TimeseriesChunk;
+
+/// Decimates `chunk` down to `threshold` points using LTTB, always keeping
+/// the first and last point. Falls back to returning `chunk` unchanged when
+/// it already has `threshold` points or fewer (nothing to decimate), and also
+/// when `threshold` is below 3 (no room for a bucket between the fixed first
+/// and last points).
+pub fn lttb(chunk: &TimeseriesChunk, threshold: usize) -> TimeseriesChunk {
+    let n = chunk.len();
+    if threshold >= n || threshold < 3 {
+        return chunk.clone();
+    }
+
+    let mut out = TimeseriesChunk::with_capacity(threshold);
+    out.timestamps.push(chunk.timestamps[0]);
+    out.values.push(chunk.values[0]);
+
+    // Bucket width in (fractional) source-index units; room is left for the
+    // fixed first/last points.
+    let every = (n - 2) as f64 / (threshold - 2) as f64;
+
+    let mut a = 0usize; // index of the previously selected point
+
+    for i in 0..threshold - 2 {
+        // Average point of the *next* bucket, used as the triangle's third
+        // vertex so the selected point favors the direction the signal is
+        // heading in.
+        let avg_range_start = (((i + 1) as f64) * every) as usize + 1;
+        let avg_range_end = ((((i + 2) as f64) * every) as usize + 1).min(n);
+        let avg_range_len = avg_range_end.saturating_sub(avg_range_start).max(1);
+
+        let mut avg_x = 0.0;
+        let mut avg_y = 0.0;
+        for j in avg_range_start..avg_range_end {
+            avg_x += chunk.timestamps[j];
+            avg_y += chunk.values[j];
+        }
+        avg_x /= avg_range_len as f64;
+        avg_y /= avg_range_len as f64;
+
+        // This bucket's own index range.
+        let range_start = ((i as f64) * every) as usize + 1;
+        let range_end = (((i + 1) as f64) * every) as usize + 1;
+
+        let (point_a_x, point_a_y) = (chunk.timestamps[a], chunk.values[a]);
+
+        let mut max_area = -1.0;
+        let mut max_area_idx = range_start;
+
+        for j in range_start..range_end {
+            let area = ((point_a_x - avg_x) * (chunk.values[j] - point_a_y)
+                - (point_a_x - chunk.timestamps[j]) * (avg_y - point_a_y))
+                .abs()
+                * 0.5;
+
+            if area > max_area {
+                max_area = area;
+                max_area_idx = j;
+            }
+        }
+
+        out.timestamps.push(chunk.timestamps[max_area_idx]);
+        out.values.push(chunk.values[max_area_idx]);
+        a = max_area_idx;
+    }
+
+    out.timestamps.push(chunk.timestamps[n - 1]);
+    out.values.push(chunk.values[n - 1]);
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunk(values: Vec<f64>) -> TimeseriesChunk {
+        TimeseriesChunk {
+            timestamps: (0..values.len()).map(|i| i as f64).collect(),
+            values,
+        }
+    }
+
+    #[test]
+    fn passes_through_when_already_at_or_below_threshold() {
+        let c = chunk(vec![1.0, 2.0, 3.0]);
+        let out = lttb(&c, 3);
+        assert_eq!(out.timestamps, c.timestamps);
+        assert_eq!(out.values, c.values);
+
+        let out = lttb(&c, 10);
+        assert_eq!(out.timestamps, c.timestamps);
+        assert_eq!(out.values, c.values);
+    }
+
+    #[test]
+    fn keeps_first_and_last_point() {
+        let c = chunk((0..100).map(|i| (i as f64).sin()).collect());
+        let out = lttb(&c, 10);
+
+        assert_eq!(out.len(), 10);
+        assert_eq!(out.timestamps.first(), c.timestamps.first());
+        assert_eq!(out.values.first(), c.values.first());
+        assert_eq!(out.timestamps.last(), c.timestamps.last());
+        assert_eq!(out.values.last(), c.values.last());
+    }
+
+    #[test]
+    fn preserves_a_sharp_peak() {
+        // A single large spike in an otherwise flat signal; LTTB's
+        // triangle-area scoring should keep it even when decimating hard.
+        let mut values = vec![0.0; 50];
+        values[25] = 1000.0;
+        let c = chunk(values);
+
+        let out = lttb(&c, 10);
+
+        assert!(out.values.iter().any(|&v| v == 1000.0));
+    }
+}
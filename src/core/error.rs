@@ -21,12 +21,22 @@ pub enum PltxError {
     #[error("Decompression failed: {0}")]
     DecompressionFailed(String),
 
+    #[error("Compression failed: {0}")]
+    CompressionFailed(String),
+
     #[error("Corrupted data: {0}")]
     CorruptedData(String),
 
     #[error("Signal not found: {0}")]
     SignalNotFound(String),
 
+    #[error("Chunk {chunk_index} not found for signal {signal_id} ({chunk_count} chunks)")]
+    ChunkNotFound {
+        signal_id: u32,
+        chunk_index: usize,
+        chunk_count: usize,
+    },
+
     #[error("Invalid UTF-8 string")]
     InvalidUtf8(#[from] std::string::FromUtf8Error),
 
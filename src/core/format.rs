@@ -38,6 +38,10 @@ pub struct ChunkHeader {
     pub compressed_length: u32,
     pub min_timestamp: f64,
     pub max_timestamp: f64,
+    /// `CompressionType` id used to compress this chunk. Only present on
+    /// disk for files with `FileHeader.version >= PER_CHUNK_CODEC_VERSION`;
+    /// older files fall back to `FileHeader.compression` for every chunk.
+    pub codec: u8,
 }
 
 #[derive(Debug, Clone)]
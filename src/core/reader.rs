@@ -1,12 +1,14 @@
 // Main PLTX reader implementation - Thread-safe version
 
+use crate::core::binary_io::{Footer, FromReader, IndexSection};
+use crate::core::chunk_cursor::ChunkCursor;
 use crate::core::compression::decompress;
 use crate::core::constants::*;
 use crate::core::error::{PltxError, Result};
 use crate::core::format::*;
 use std::collections::HashMap;
 use std::fs::File;
-use std::io::{Read, Seek, SeekFrom};
+use std::io::{Cursor, Read, Seek, SeekFrom};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
@@ -35,115 +37,25 @@ impl PltxReader {
     }
 
     fn read_header(file: &mut File) -> Result<FileHeader> {
-        // Read header prefix
-        let mut prefix = [0u8; HEADER_PREFIX_SIZE];
-        file.read_exact(&mut prefix)?;
-
-        // Parse prefix
-        let magic = &prefix[0..4];
-        if magic != MAGIC {
-            return Err(PltxError::InvalidMagic {
-                expected: MAGIC.to_vec(),
-                got: magic.to_vec(),
-            });
-        }
-
-        let version = prefix[4];
-        let compression = prefix[5];
-        let created = f64::from_le_bytes(prefix[6..14].try_into().unwrap());
-        let sig_count = u16::from_le_bytes(prefix[14..16].try_into().unwrap());
-
-        // Read signal metadata
-        let mut signals = HashMap::new();
-        for _ in 0..sig_count {
-            let mut sid_buf = [0u8; 4];
-            file.read_exact(&mut sid_buf)?;
-            let signal_id = u32::from_le_bytes(sid_buf);
-
-            let name = Self::read_string(file)?;
-            let unit = Self::read_string(file)?;
-            let description = Self::read_string(file)?;
-            let source = Self::read_string(file)?;
-
-            signals.insert(
-                signal_id,
-                SignalMetadata {
-                    name,
-                    unit,
-                    description,
-                    source,
-                },
-            );
-        }
-
-        Ok(FileHeader {
-            version,
-            compression,
-            created,
-            signals,
-        })
-    }
-
-    fn read_string(file: &mut File) -> Result<String> {
-        let mut len_buf = [0u8; 2];
-        file.read_exact(&mut len_buf)?;
-        let len = u16::from_le_bytes(len_buf) as usize;
-
-        let mut str_buf = vec![0u8; len];
-        file.read_exact(&mut str_buf)?;
-
-        String::from_utf8(str_buf).map_err(|e| e.into())
+        FileHeader::from_reader(file)
     }
 
     fn read_footer_and_index(file: &mut File) -> Result<HashMap<u32, Vec<IndexEntry>>> {
-        // Seek to footer
-        file.seek(SeekFrom::End(-(FOOTER_SIZE as i64)))?;
-
-        let mut footer = [0u8; FOOTER_SIZE];
-        file.read_exact(&mut footer)?;
-
-        let footer_magic = &footer[0..4];
-        if footer_magic != FOOTER_MAGIC {
-            return Err(PltxError::InvalidMagic {
-                expected: FOOTER_MAGIC.to_vec(),
-                got: footer_magic.to_vec(),
-            });
-        }
-
-        let index_offset = u64::from_le_bytes(footer[4..12].try_into().unwrap());
+        let footer = Footer::from_reader(file)?;
+        file.seek(SeekFrom::Start(footer.index_offset))?;
 
-        // Seek to index
-        file.seek(SeekFrom::Start(index_offset))?;
+        let index_section = IndexSection::from_reader(file)?;
 
-        let mut index_magic = [0u8; 4];
-        file.read_exact(&mut index_magic)?;
-        if &index_magic != INDEX_MAGIC {
-            return Err(PltxError::InvalidMagic {
-                expected: INDEX_MAGIC.to_vec(),
-                got: index_magic.to_vec(),
-            });
+        let mut index: HashMap<u32, Vec<IndexEntry>> = HashMap::new();
+        for entry in index_section.entries {
+            index.entry(entry.signal_id).or_insert_with(Vec::new).push(entry);
         }
 
-        let mut count_buf = [0u8; 4];
-        file.read_exact(&mut count_buf)?;
-        let entry_count = u32::from_le_bytes(count_buf);
-
-        let mut index: HashMap<u32, Vec<IndexEntry>> = HashMap::new();
-        for _ in 0..entry_count {
-            let mut entry_buf = [0u8; INDEX_ENTRY_SIZE];
-            file.read_exact(&mut entry_buf)?;
-
-            let signal_id = u32::from_le_bytes(entry_buf[0..4].try_into().unwrap());
-            let offset = u64::from_le_bytes(entry_buf[4..12].try_into().unwrap());
-            let min_ts = f64::from_le_bytes(entry_buf[12..20].try_into().unwrap());
-            let max_ts = f64::from_le_bytes(entry_buf[20..28].try_into().unwrap());
-
-            index.entry(signal_id).or_insert_with(Vec::new).push(IndexEntry {
-                signal_id,
-                offset,
-                min_timestamp: min_ts,
-                max_timestamp: max_ts,
-            });
+        // Sorted by `min_timestamp` so `read_time_range`/`read_time_range_chunks`
+        // can binary-search for the first overlapping entry instead of
+        // scanning every chunk in the signal.
+        for entries in index.values_mut() {
+            entries.sort_by(|a, b| a.min_timestamp.total_cmp(&b.min_timestamp));
         }
 
         Ok(index)
@@ -211,6 +123,44 @@ impl PltxReader {
         Ok(chunks)
     }
 
+    /// Lazy, chunk-by-chunk variant of [`PltxReader::read_time_range`].
+    ///
+    /// Chunks whose `[min_timestamp, max_timestamp]` index entry falls entirely
+    /// outside `[from, to]` are skipped without ever being decompressed; chunks
+    /// that straddle a boundary are decompressed and have their out-of-range
+    /// records filtered. `from`/`to` of `None` behave as unbounded.
+    pub fn read_signal_chunks_in_range(
+        &self,
+        signal_id: u32,
+        from: Option<f64>,
+        to: Option<f64>,
+    ) -> Result<RangeChunkStream> {
+        let from = from.unwrap_or(f64::NEG_INFINITY);
+        let to = to.unwrap_or(f64::INFINITY);
+
+        let entries: Vec<IndexEntry> = self
+            .index
+            .get(&signal_id)
+            .ok_or_else(|| PltxError::SignalNotFound(signal_id.to_string()))?
+            .iter()
+            .filter(|entry| entry.max_timestamp >= from && entry.min_timestamp <= to)
+            .cloned()
+            .collect();
+
+        let compression = CompressionType::from_u8(self.header.compression)
+            .ok_or(PltxError::UnsupportedCompression(self.header.compression))?;
+
+        Ok(RangeChunkStream {
+            file: self.file.clone(),
+            compression,
+            version: self.header.version,
+            entries,
+            next: 0,
+            from,
+            to,
+        })
+    }
+
     pub fn read_time_range(
         &self,
         signal_id: u32,
@@ -227,12 +177,7 @@ impl PltxReader {
 
         let mut result = TimeseriesChunk::new();
 
-        for entry in entries {
-            // Skip chunks outside time range
-            if entry.max_timestamp < start_time || entry.min_timestamp > end_time {
-                continue;
-            }
-
+        for entry in overlapping_entries(entries, start_time, end_time) {
             let chunk = self.read_chunk_at(entry.offset, compression)?;
 
             // Filter records within time range
@@ -247,51 +192,207 @@ impl PltxReader {
         Ok(result)
     }
 
+    /// Like [`PltxReader::read_time_range`] but returns the overlapping chunks
+    /// whole, without filtering out-of-range records from the edge chunks.
+    /// Useful for callers (e.g. LTTB decimation) that want to do their own
+    /// windowing instead of paying for a per-record filter here.
+    pub fn read_time_range_chunks(
+        &self,
+        signal_id: u32,
+        start_time: f64,
+        end_time: f64,
+    ) -> Result<Vec<TimeseriesChunk>> {
+        let entries = self
+            .index
+            .get(&signal_id)
+            .ok_or_else(|| PltxError::SignalNotFound(signal_id.to_string()))?;
+
+        let compression = CompressionType::from_u8(self.header.compression)
+            .ok_or(PltxError::UnsupportedCompression(self.header.compression))?;
+
+        overlapping_entries(entries, start_time, end_time)
+            .map(|entry| self.read_chunk_at(entry.offset, compression))
+            .collect()
+    }
+
     fn read_chunk_at(&self, offset: u64, compression: CompressionType) -> Result<TimeseriesChunk> {
-        let mut file = self.file.lock().unwrap();
-        
-        file.seek(SeekFrom::Start(offset))?;
-
-        let mut chunk_magic = [0u8; 4];
-        file.read_exact(&mut chunk_magic)?;
-        if &chunk_magic != CHUNK_MAGIC {
-            return Err(PltxError::CorruptedData(
-                "Invalid chunk magic".to_string(),
-            ));
-        }
+        read_chunk_at_shared(&self.file, offset, compression, self.header.version)
+    }
 
-        let mut header_buf = [0u8; CHUNK_HEADER_SIZE];
-        file.read_exact(&mut header_buf)?;
+    /// Returns a lazy, one-chunk-at-a-time iterator over a signal's chunks.
+    ///
+    /// Unlike [`PltxReader::read_signal_chunks`], this does not materialize every
+    /// chunk up front: each call to `next()` seeks to the next `IndexEntry.offset`
+    /// and decompresses only that chunk, re-acquiring the file lock briefly per
+    /// chunk rather than holding it for the whole signal.
+    pub fn chunk_stream(&self, signal_id: u32) -> Result<ChunkStream> {
+        let entries = self
+            .index
+            .get(&signal_id)
+            .ok_or_else(|| PltxError::SignalNotFound(signal_id.to_string()))?
+            .clone();
 
-        let _signal_id = u32::from_le_bytes(header_buf[0..4].try_into().unwrap());
-        let record_count = u32::from_le_bytes(header_buf[4..8].try_into().unwrap());
-        let raw_length = u32::from_le_bytes(header_buf[8..12].try_into().unwrap());
-        let compressed_length = u32::from_le_bytes(header_buf[12..16].try_into().unwrap());
+        let compression = CompressionType::from_u8(self.header.compression)
+            .ok_or(PltxError::UnsupportedCompression(self.header.compression))?;
 
-        let mut compressed_data = vec![0u8; compressed_length as usize];
-        file.read_exact(&mut compressed_data)?;
+        Ok(ChunkStream {
+            file: self.file.clone(),
+            compression,
+            version: self.header.version,
+            entries,
+            next: 0,
+        })
+    }
 
-        let raw_data = decompress(&compressed_data, compression)?;
+    /// Returns a bounded, seekable cursor over the `chunk_index`-th chunk of
+    /// `signal_id`, counted in the index's `min_timestamp` order (the order
+    /// `PltxReader::open` sorts each signal's entries into), for random access to a single record
+    /// without decoding the whole chunk into a `TimeseriesChunk`.
+    pub fn chunk_cursor(&self, signal_id: u32, chunk_index: usize) -> Result<ChunkCursor> {
+        let entries = self
+            .index
+            .get(&signal_id)
+            .ok_or_else(|| PltxError::SignalNotFound(signal_id.to_string()))?;
 
-        if raw_data.len() != raw_length as usize {
-            return Err(PltxError::CorruptedData(format!(
-                "Expected {} bytes, got {}",
-                raw_length,
-                raw_data.len()
-            )));
-        }
+        let entry = entries.get(chunk_index).ok_or(PltxError::ChunkNotFound {
+            signal_id,
+            chunk_index,
+            chunk_count: entries.len(),
+        })?;
 
-        let mut chunk = TimeseriesChunk::with_capacity(record_count as usize);
+        let compression = CompressionType::from_u8(self.header.compression)
+            .ok_or(PltxError::UnsupportedCompression(self.header.compression))?;
 
-        for i in 0..record_count as usize {
-            let offset = i * RECORD_SIZE;
-            let ts = f64::from_le_bytes(raw_data[offset..offset + 8].try_into().unwrap());
-            let val = f64::from_le_bytes(raw_data[offset + 8..offset + 16].try_into().unwrap());
-            chunk.timestamps.push(ts);
-            chunk.values.push(val);
-        }
+        ChunkCursor::open(&self.file, entry.offset, compression, self.header.version)
+    }
+}
+
+/// Returns the entries (assumed sorted by `min_timestamp`, as `PltxReader::open`
+/// leaves them) whose `[min_timestamp, max_timestamp]` overlaps `[start_time,
+/// end_time]`, without scanning entries that can't possibly overlap.
+///
+/// Chunks can overlap in time, so the lower-bound search on `min_timestamp`
+/// alone isn't enough: some entry far earlier in the sort order (not just the
+/// immediately preceding one) might still stretch its `max_timestamp` into
+/// `start_time`. We find the leftmost entry at or after which the running
+/// (prefix) max of `max_timestamp` reaches `start_time`, then iterate forward,
+/// skipping entries that end before `start_time`, and stop as soon as
+/// `min_timestamp` passes `end_time`.
+fn overlapping_entries(
+    entries: &[IndexEntry],
+    start_time: f64,
+    end_time: f64,
+) -> impl Iterator<Item = &IndexEntry> {
+    let mut running_max = f64::NEG_INFINITY;
+    let prefix_max: Vec<f64> = entries
+        .iter()
+        .map(|e| {
+            running_max = running_max.max(e.max_timestamp);
+            running_max
+        })
+        .collect();
+    let start = prefix_max.partition_point(|&m| m < start_time);
 
-        Ok(chunk)
+    entries[start..]
+        .iter()
+        .take_while(move |e| e.min_timestamp <= end_time)
+        .filter(move |e| e.max_timestamp >= start_time)
+}
+
+fn read_chunk_at_shared(
+    file: &Arc<Mutex<File>>,
+    offset: u64,
+    file_compression: CompressionType,
+    version: u8,
+) -> Result<TimeseriesChunk> {
+    let mut file = file.lock().unwrap();
+
+    file.seek(SeekFrom::Start(offset))?;
+
+    let header = ChunkHeader::from_reader(&mut *file)?;
+
+    // Files written after per-chunk codecs were introduced carry one extra
+    // codec byte right after the fixed-size header; older files share the
+    // single file-level codec instead.
+    let compression = if version >= PER_CHUNK_CODEC_VERSION {
+        let mut codec_buf = [0u8; CHUNK_CODEC_SIZE];
+        file.read_exact(&mut codec_buf)?;
+        CompressionType::from_u8(codec_buf[0])
+            .ok_or(PltxError::UnsupportedCompression(codec_buf[0]))?
+    } else {
+        file_compression
+    };
+
+    let mut compressed_data = vec![0u8; header.compressed_length as usize];
+    file.read_exact(&mut compressed_data)?;
+
+    let raw_data = decompress(&compressed_data, compression)?;
+
+    if raw_data.len() != header.raw_length as usize {
+        return Err(PltxError::CorruptedData(format!(
+            "Expected {} bytes, got {}",
+            header.raw_length,
+            raw_data.len()
+        )));
+    }
+
+    TimeseriesChunk::from_reader(&mut Cursor::new(raw_data))
+}
+
+/// Lazy iterator over a signal's chunks, yielding one decoded [`TimeseriesChunk`]
+/// per `next()` call instead of buffering the whole signal in memory.
+pub struct ChunkStream {
+    file: Arc<Mutex<File>>,
+    compression: CompressionType,
+    version: u8,
+    entries: Vec<IndexEntry>,
+    next: usize,
+}
+
+impl Iterator for ChunkStream {
+    type Item = Result<TimeseriesChunk>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.entries.get(self.next)?;
+        self.next += 1;
+        Some(read_chunk_at_shared(&self.file, entry.offset, self.compression, self.version))
+    }
+}
+
+/// Lazy iterator over a signal's chunks restricted to a `[from, to]` timestamp
+/// window; see [`PltxReader::read_signal_chunks_in_range`].
+pub struct RangeChunkStream {
+    file: Arc<Mutex<File>>,
+    compression: CompressionType,
+    version: u8,
+    entries: Vec<IndexEntry>,
+    next: usize,
+    from: f64,
+    to: f64,
+}
+
+impl Iterator for RangeChunkStream {
+    type Item = Result<TimeseriesChunk>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.entries.get(self.next)?;
+        self.next += 1;
+
+        Some(read_chunk_at_shared(&self.file, entry.offset, self.compression, self.version).map(|chunk| {
+            // Fully-contained chunks need no per-record filtering.
+            if entry.min_timestamp >= self.from && entry.max_timestamp <= self.to {
+                return chunk;
+            }
+
+            let mut filtered = TimeseriesChunk::with_capacity(chunk.len());
+            for (ts, val) in chunk.timestamps.iter().zip(chunk.values.iter()) {
+                if *ts >= self.from && *ts <= self.to {
+                    filtered.timestamps.push(*ts);
+                    filtered.values.push(*val);
+                }
+            }
+            filtered
+        }))
     }
 }
 
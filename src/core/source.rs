@@ -0,0 +1,44 @@
+// Pluggable storage backends for opening PLTX files from sources other than
+// the local filesystem (remote object stores, HTTP ranges, etc).
+//
+// Only `"file"` is registered by `AppState::new` - an embedder that wants
+// `http://`/`s3://`/etc. paths to resolve must register its own `PltxSource`
+// impl for that scheme in `AppState.sources` (`/read-file` 400s on an
+// unregistered scheme).
+
+use async_trait::async_trait;
+
+use crate::core::error::{PltxError, Result};
+use crate::core::reader::PltxReader;
+
+/// A storage backend capable of producing a [`PltxReader`] for a path
+/// understood by its scheme.
+#[async_trait]
+pub trait PltxSource: Send + Sync {
+    /// Opens `path` (with any scheme prefix already stripped) and returns a
+    /// ready-to-use reader.
+    async fn open(&self, path: &str) -> Result<PltxReader>;
+}
+
+/// Default backend: opens files from the local filesystem. This is what
+/// `read_file` used before backends were pluggable.
+pub struct LocalSource;
+
+#[async_trait]
+impl PltxSource for LocalSource {
+    async fn open(&self, path: &str) -> Result<PltxReader> {
+        let path = path.to_owned();
+        tokio::task::spawn_blocking(move || PltxReader::open(&path))
+            .await
+            .map_err(|e| PltxError::CorruptedData(format!("open task panicked: {}", e)))?
+    }
+}
+
+/// Splits a `scheme://rest` path into its scheme and the remainder, defaulting
+/// to `"file"` when no `://` prefix is present (plain local paths).
+pub fn split_scheme(path: &str) -> (&str, &str) {
+    match path.split_once("://") {
+        Some((scheme, rest)) => (scheme, rest),
+        None => ("file", path),
+    }
+}
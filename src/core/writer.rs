@@ -0,0 +1,257 @@
+// Writes valid PLTX files: the inverse of `PltxReader`.
+
+use std::collections::HashMap;
+use std::io::{Seek, Write};
+use std::path::Path;
+
+use crate::core::binary_io::{Footer, FromReader as _, IndexSection, ToWriter};
+use crate::core::compression::compress;
+use crate::core::constants::*;
+use crate::core::error::Result;
+use crate::core::format::{ChunkHeader, FileHeader, IndexEntry, SignalMetadata, TimeseriesChunk};
+
+/// Builds a PLTX file over any `Write + Seek` sink, registering signals up
+/// front and then appending points per signal.
+///
+/// Appended points are buffered per signal and flushed to disk as a
+/// compressed chunk once they pass `flush_threshold` records, so a long
+/// recording never needs to be held in memory all at once. The index and
+/// footer are written last, once every chunk's offset is known.
+pub struct PltxWriter<W: Write + Seek> {
+    writer: W,
+    compression: CompressionType,
+    flush_threshold: usize,
+    signals: HashMap<u32, SignalMetadata>,
+    header_written: bool,
+    pending: HashMap<u32, TimeseriesChunk>,
+    index: Vec<IndexEntry>,
+}
+
+const DEFAULT_FLUSH_THRESHOLD: usize = 4096;
+
+impl<W: Write + Seek> PltxWriter<W> {
+    pub fn new(writer: W, compression: CompressionType) -> Self {
+        Self {
+            writer,
+            compression,
+            flush_threshold: DEFAULT_FLUSH_THRESHOLD,
+            signals: HashMap::new(),
+            header_written: false,
+            pending: HashMap::new(),
+            index: Vec::new(),
+        }
+    }
+
+    /// Sets the record count a signal's buffered points must pass before
+    /// they're compressed and flushed to disk as a chunk.
+    pub fn with_flush_threshold(mut self, flush_threshold: usize) -> Self {
+        self.flush_threshold = flush_threshold.max(1);
+        self
+    }
+
+    /// Registers a signal's metadata. Must be called for every signal before
+    /// the first `append_chunk`/`finish` call, since the file header (which
+    /// lists every signal) is written as soon as any bytes need to go out.
+    pub fn register_signal(&mut self, signal_id: u32, metadata: SignalMetadata) {
+        self.signals.insert(signal_id, metadata);
+    }
+
+    /// Appends points to `signal_id`, auto-flushing completed chunks as the
+    /// buffered count passes `flush_threshold` (possibly more than once, if
+    /// this call alone pushes the buffer past several thresholds).
+    pub fn append_chunk(&mut self, signal_id: u32, chunk: TimeseriesChunk) -> Result<()> {
+        self.ensure_header_written()?;
+
+        let buffered = self
+            .pending
+            .entry(signal_id)
+            .or_insert_with(TimeseriesChunk::new);
+        buffered.timestamps.extend(chunk.timestamps);
+        buffered.values.extend(chunk.values);
+
+        while self.pending.get(&signal_id).map_or(false, |b| b.len() >= self.flush_threshold) {
+            let to_flush = take_front(self.pending.get_mut(&signal_id).unwrap(), self.flush_threshold);
+            self.flush_chunk(signal_id, to_flush)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the header prefix (`MAGIC`, version, file-level compression,
+    /// signal table) the first time any data needs to be written. Signals
+    /// registered after this point won't appear in the header.
+    fn ensure_header_written(&mut self) -> Result<()> {
+        if self.header_written {
+            return Ok(());
+        }
+
+        let created = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs_f64())
+            .unwrap_or(0.0);
+
+        let header = FileHeader {
+            version: CURRENT_VERSION,
+            compression: self.compression as u8,
+            created,
+            signals: self.signals.clone(),
+        };
+
+        header.to_writer(&mut self.writer)?;
+        self.header_written = true;
+        Ok(())
+    }
+
+    /// Compresses and writes one physical chunk, recording its offset in the
+    /// in-memory index for the footer written by `finish`.
+    fn flush_chunk(&mut self, signal_id: u32, chunk: TimeseriesChunk) -> Result<()> {
+        if chunk.is_empty() {
+            return Ok(());
+        }
+
+        let min_timestamp = chunk.timestamps.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max_timestamp = chunk
+            .timestamps
+            .iter()
+            .cloned()
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        let mut raw = Vec::with_capacity(chunk.len() * RECORD_SIZE);
+        chunk.to_writer(&mut raw)?;
+
+        let compressed = compress(&raw, self.compression)?;
+
+        let offset = self.writer.stream_position()?;
+
+        let header = ChunkHeader {
+            signal_id,
+            record_count: chunk.len() as u32,
+            raw_length: raw.len() as u32,
+            compressed_length: compressed.len() as u32,
+            min_timestamp,
+            max_timestamp,
+            codec: self.compression as u8,
+        };
+        header.to_writer(&mut self.writer)?;
+        // Per-chunk codec byte, read back by `PltxReader` for
+        // `FileHeader.version >= PER_CHUNK_CODEC_VERSION`.
+        self.writer.write_all(&[self.compression as u8])?;
+        self.writer.write_all(&compressed)?;
+
+        self.index.push(IndexEntry {
+            signal_id,
+            offset,
+            min_timestamp,
+            max_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Flushes every signal's remaining buffered points as a final (possibly
+    /// short) chunk, then writes the index block and footer.
+    pub fn finish(mut self) -> Result<()> {
+        self.ensure_header_written()?;
+
+        let signal_ids: Vec<u32> = self.pending.keys().copied().collect();
+        for signal_id in signal_ids {
+            if let Some(chunk) = self.pending.remove(&signal_id) {
+                self.flush_chunk(signal_id, chunk)?;
+            }
+        }
+
+        let index_offset = self.writer.stream_position()?;
+        let index_section = IndexSection {
+            entries: self.index.clone(),
+        };
+        index_section.to_writer(&mut self.writer)?;
+
+        let footer = Footer { index_offset };
+        footer.to_writer(&mut self.writer)?;
+
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+impl PltxWriter<std::fs::File> {
+    pub fn create<P: AsRef<Path>>(path: P, compression: CompressionType) -> Result<Self> {
+        let file = std::fs::File::create(path)?;
+        Ok(Self::new(file, compression))
+    }
+}
+
+/// Drains the first `n` points out of `chunk`, leaving the remainder.
+fn take_front(chunk: &mut TimeseriesChunk, n: usize) -> TimeseriesChunk {
+    let n = n.min(chunk.len());
+    TimeseriesChunk {
+        timestamps: chunk.timestamps.drain(0..n).collect(),
+        values: chunk.values.drain(0..n).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::reader::PltxReader;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("pltx_writer_test_{}_{}.pltx", std::process::id(), name))
+    }
+
+    #[test]
+    fn round_trips_a_single_signal() {
+        let path = temp_path("single_signal");
+
+        let mut writer = PltxWriter::create(&path, CompressionType::None).unwrap();
+        writer.register_signal(1, SignalMetadata::new("temperature".to_string()));
+
+        let chunk = TimeseriesChunk {
+            timestamps: vec![1.0, 2.0, 3.0],
+            values: vec![10.0, 20.0, 30.0],
+        };
+        writer.append_chunk(1, chunk.clone()).unwrap();
+        writer.finish().unwrap();
+
+        let reader = PltxReader::open(&path).unwrap();
+        let signal_id = reader.get_signal_id_by_name("temperature").unwrap();
+        let read_back = reader.read_signal_all(signal_id).unwrap();
+
+        assert_eq!(read_back.timestamps, chunk.timestamps);
+        assert_eq!(read_back.values, chunk.values);
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn flushes_across_the_threshold_transparently() {
+        let path = temp_path("threshold");
+
+        let mut writer = PltxWriter::create(&path, CompressionType::None)
+            .unwrap()
+            .with_flush_threshold(4);
+        writer.register_signal(1, SignalMetadata::new("pressure".to_string()));
+
+        let timestamps: Vec<f64> = (0..10).map(|i| i as f64).collect();
+        let values: Vec<f64> = (0..10).map(|i| (i * 2) as f64).collect();
+        writer
+            .append_chunk(
+                1,
+                TimeseriesChunk {
+                    timestamps: timestamps.clone(),
+                    values: values.clone(),
+                },
+            )
+            .unwrap();
+        writer.finish().unwrap();
+
+        let reader = PltxReader::open(&path).unwrap();
+        let signal_id = reader.get_signal_id_by_name("pressure").unwrap();
+        let read_back = reader.read_signal_all(signal_id).unwrap();
+
+        assert_eq!(read_back.timestamps, timestamps);
+        assert_eq!(read_back.values, values);
+
+        std::fs::remove_file(&path).ok();
+    }
+}
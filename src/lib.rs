@@ -4,10 +4,21 @@
 pub mod core;
 
 // Re-export main types
+pub use core::backend::SignalBackend;
 pub use core::error::{PltxError, Result};
-pub use core::reader::PltxReader;
-pub use core::format::{SignalMetadata, IndexEntry};
-pub use core::data_handle::{handle_ws_fetch};
+pub use core::chunk_cursor::ChunkCursor;
+pub use core::reader::{ChunkStream, PltxReader, RangeChunkStream};
+pub use core::format::{SignalMetadata, IndexEntry, TimeseriesChunk};
+pub use core::data_handle::{decode_binary_frame, handle_ws_fetch, FetchProtocol, FetchRange};
+pub use core::downsample::lttb;
+pub use core::writer::PltxWriter;
+pub use core::source;
+
+/// `AsyncPltxReader`, the `tokio::io::{AsyncRead, AsyncSeek}`-based
+/// counterpart to `PltxReader`; only built when the `async-reader` feature
+/// is enabled.
+#[cfg(feature = "async-reader")]
+pub use core::async_reader::AsyncPltxReader;
 
 #[cfg(test)]
 mod tests {
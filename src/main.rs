@@ -7,6 +7,7 @@ mod models;
 mod utils;
 mod client;
 mod state;
+mod auth;
 
 use crate::utils::conf_helper::{init_config_and_bind, get_cached_config};
 use crate::state::app_state::AppState;
@@ -39,7 +40,7 @@ async fn main() {
     });
 
     let app = Router::new()
-        .merge(routes::info_routes::health_routes())
+        .merge(routes::info_routes::health_routes(state.clone()))
         .merge(routes::data_routes::data_routes(state.clone()));
 
     axum::serve(listener, app)
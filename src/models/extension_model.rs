@@ -20,6 +20,16 @@ pub struct ExtensionConfig {
     pub ask_form: bool,
     pub connection: Connection,
     pub configuration: Value,
+    #[serde(default = "default_enable_compression")]
+    pub enable_compression: bool,
+    /// Shared bearer token accepted by the default `TokenAuth` backend.
+    /// Never echoed back in `/info` responses.
+    #[serde(default, skip_serializing)]
+    pub api_token: String,
+}
+
+fn default_enable_compression() -> bool {
+    true
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -0,0 +1,123 @@
+// Response compression middleware (deflate/gzip) for JSON route bodies
+
+use axum::{
+    body::{to_bytes, Body},
+    extract::Request,
+    http::{header, HeaderValue, StatusCode},
+    middleware::Next,
+    response::Response,
+};
+use flate2::{
+    write::{DeflateEncoder, GzEncoder},
+    Compression,
+};
+use std::io::Write;
+use tracing::warn;
+
+use crate::utils::conf_helper::get_cached_config;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ContentEncoding {
+    Gzip,
+    Deflate,
+}
+
+impl ContentEncoding {
+    fn as_header_value(self) -> HeaderValue {
+        match self {
+            ContentEncoding::Gzip => HeaderValue::from_static("gzip"),
+            ContentEncoding::Deflate => HeaderValue::from_static("deflate"),
+        }
+    }
+}
+
+// Picks the first encoding we support from the client's `Accept-Encoding` list,
+// in the order the client lists them; gzip is not ranked above deflate. Respects an
+// explicit `;q=0`, which means the client refuses that coding outright.
+fn negotiate(accept_encoding: &str) -> Option<ContentEncoding> {
+    accept_encoding
+        .split(',')
+        .filter_map(|part| {
+            let mut params = part.split(';');
+            let token = params.next().unwrap_or("").trim();
+            let q: f32 = params
+                .find_map(|p| p.trim().strip_prefix("q="))
+                .and_then(|v| v.trim().parse().ok())
+                .unwrap_or(1.0);
+            (q > 0.0).then_some(token)
+        })
+        .find_map(|token| match token {
+            "gzip" => Some(ContentEncoding::Gzip),
+            "deflate" => Some(ContentEncoding::Deflate),
+            _ => None,
+        })
+}
+
+fn compress(body: &[u8], encoding: ContentEncoding) -> std::io::Result<Vec<u8>> {
+    match encoding {
+        ContentEncoding::Gzip => {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(body)?;
+            encoder.finish()
+        }
+        ContentEncoding::Deflate => {
+            let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(body)?;
+            encoder.finish()
+        }
+    }
+}
+
+/// Axum middleware applied to the data/health routers: negotiates `Accept-Encoding`
+/// against gzip/deflate and compresses the serialized response body in place.
+/// Disabled entirely when `ExtensionConfig.enable_compression` is `false`.
+pub async fn compression_layer(req: Request, next: Next) -> Response {
+    let config = get_cached_config();
+    if !config.enable_compression {
+        return next.run(req).await;
+    }
+
+    let encoding = req
+        .headers()
+        .get(header::ACCEPT_ENCODING)
+        .and_then(|v| v.to_str().ok())
+        .and_then(negotiate);
+
+    let Some(encoding) = encoding else {
+        return next.run(req).await;
+    };
+
+    let response = next.run(req).await;
+
+    // Never touch a WebSocket handshake (101 Switching Protocols) or any
+    // response other than 200 OK (including other 2xx codes, empty/error
+    // bodies and redirects) - rebuilding those
+    // with a compressed body would corrupt the upgrade or misrepresent the
+    // response.
+    if response.status() != StatusCode::OK {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+
+    let bytes = match to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            warn!("compression_layer: failed to buffer response body: {}", e);
+            return Response::from_parts(parts, Body::empty());
+        }
+    };
+
+    let compressed = match compress(&bytes, encoding) {
+        Ok(compressed) => compressed,
+        Err(e) => {
+            warn!("compression_layer: compression failed, sending uncompressed: {}", e);
+            return Response::from_parts(parts, Body::from(bytes));
+        }
+    };
+
+    parts.headers.insert(header::CONTENT_ENCODING, encoding.as_header_value());
+    parts.headers.remove(header::CONTENT_LENGTH);
+
+    Response::from_parts(parts, Body::from(compressed))
+}
@@ -6,6 +6,7 @@ use axum::{
     Json,
     extract::{
         Path,
+        Query,
         State,
         ws::WebSocketUpgrade,
     },
@@ -17,11 +18,23 @@ use tracing::{info, debug, error};
 use serde::{Serialize, Deserialize};
 
 use crate::state::app_state::AppState;
-use pltx_reader::PltxReader;
 
-use crate::routes::ws_handler::handle_ws_fetch;
+use pltx_reader::{handle_ws_fetch, FetchProtocol, FetchRange};
 use std::collections::HashMap;
 
+/// Query parameters accepted by `GET /fetch/{signal}`.
+#[derive(Deserialize, Debug, Default)]
+pub struct FetchQuery {
+    pub from: Option<f64>,
+    pub to: Option<f64>,
+    /// `"binary"` switches to the binary frame protocol; anything else (or
+    /// absent) keeps the default JSON-per-point protocol.
+    pub format: Option<String>,
+    /// Decimates the signal to this many visually representative points via
+    /// LTTB before streaming; absent sends every point.
+    pub points: Option<usize>,
+}
+
 #[derive(Serialize)]
 pub struct ReaderSummary {
     pub id: String,            // hex pointer id
@@ -42,6 +55,16 @@ pub struct FileReadRequest {
     pub path: String,
 }
 
+/// Request body for `POST /read-remote`: federate one signal from another
+/// Plotune reader instance's `/fetch/{signal}` endpoint.
+#[derive(Deserialize, Debug)]
+pub struct RemoteReadRequest {
+    /// Base URL of the remote instance, e.g. `ws://10.0.0.5:9001`.
+    pub base_url: String,
+    /// Name of the signal to federate, as exposed by the remote instance.
+    pub signal: String,
+}
+
 #[derive(Serialize, Debug)]
 pub struct FileReadResponse {
     pub id: String,
@@ -61,11 +84,20 @@ pub struct FileReadResponse {
 /// =======================
 
 pub fn data_routes(state: AppState) -> Router {
-    Router::new()
+    // `/fetch/{signal}` is a WebSocket upgrade, not a JSON response - running
+    // it through the JSON compression middleware would buffer/rewrite the
+    // 101 Switching Protocols handshake and break the upgrade.
+    let ws = Router::new().route("/fetch/{:signal}", get(ws_fetch));
+
+    let json = Router::new()
         .route("/read-file", post(read_file))
-        .route("/fetch/{:signal}", get(ws_fetch))
+        .route("/read-remote", post(read_remote))
         .route("/readers", get(list_readers))
         .route("/readers/{:id}/headers", get(reader_headers))
+        .layer(axum::middleware::from_fn(crate::routes::compression::compression_layer));
+
+    ws.merge(json)
+        .layer(axum::middleware::from_fn_with_state(state.clone(), crate::auth::auth_layer))
         .with_state(state)
 }
 
@@ -80,28 +112,38 @@ async fn read_file(
 ) -> Response {
     debug!("Reading file: mode={}, path={}", request.mode, request.path);
 
-    // Open reader ONCE and wrap in Arc<Mutex>
-    let reader = match PltxReader::open(&request.path) {
-        Ok(r) => Arc::new(tokio::sync::Mutex::new(r)),
+    let (scheme, bare_path) = pltx_reader::source::split_scheme(&request.path);
+    let source = match state.sources.get(scheme) {
+        Some(source) => source.clone(),
+        None => {
+            error!("No registered source for scheme: {}", scheme);
+            return StatusCode::BAD_REQUEST.into_response();
+        }
+    };
+
+    // Open the reader once; list its signals before it moves behind the trait object
+    let reader = match source.open(bare_path).await {
+        Ok(r) => r,
         Err(e) => {
             error!("Failed to open file {}: {}", request.path, e);
             return StatusCode::INTERNAL_SERVER_ERROR.into_response();
         }
     };
 
+    let signal_list: Vec<(u32, String)> = reader
+        .list_signals()
+        .into_iter()
+        .map(|(id, name)| (id, name.to_string()))
+        .collect();
+
+    // Wrap in Arc<Mutex<dyn SignalBackend>> so this group can be merged into
+    // the same registry as federated remote readers (see `/read-remote`)
+    let reader: Arc<tokio::sync::Mutex<dyn pltx_reader::SignalBackend>> =
+        Arc::new(tokio::sync::Mutex::new(reader));
+
     let mut exposed_headers = Vec::new();
     let mut signals = state.signals.write().await;
 
-    // 🔒 Lock the reader to get signal list
-    // Convert &str to String to own the data
-    let signal_list: Vec<(u32, String)> = {
-        let reader_guard = reader.lock().await;
-        reader_guard.list_signals()
-            .into_iter()
-            .map(|(id, name)| (id, name.to_string()))
-            .collect()
-    }; // Lock released here
-
     // Iterate through signal names
     for (_id, name) in signal_list {
         let base_name = name;  // Already a String now
@@ -159,9 +201,77 @@ async fn read_file(
 }
 
 
+async fn read_remote(
+    State(state): State<AppState>,
+    Json(request): Json<RemoteReadRequest>,
+) -> Response {
+    debug!(
+        "Federating remote signal: base_url={}, signal={}",
+        request.base_url, request.signal
+    );
+
+    let reader = match crate::client::remote_reader::PltxRemoteReader::connect(
+        &request.base_url,
+        &request.signal,
+    )
+    .await
+    {
+        Ok(r) => r,
+        Err(e) => {
+            error!("Failed to federate {} from {}: {}", request.signal, request.base_url, e);
+            return StatusCode::BAD_GATEWAY.into_response();
+        }
+    };
+
+    // Wrap in the same trait object a local reader would use, so
+    // `list_readers` groups it by pointer like any other reader.
+    let reader: Arc<tokio::sync::Mutex<dyn pltx_reader::SignalBackend>> =
+        Arc::new(tokio::sync::Mutex::new(reader));
+
+    let base_name = request.signal.clone();
+    let mut signals = state.signals.write().await;
+
+    let mut final_name = base_name.clone();
+    if signals.contains_key(&final_name) {
+        let mut i = 1;
+        loop {
+            let candidate = format!("{}_{}", base_name, i);
+            if !signals.contains_key(&candidate) {
+                final_name = candidate;
+                break;
+            }
+            i += 1;
+        }
+    }
+
+    info!("Register remote signal: {} (original: {})", final_name, base_name);
+
+    signals.insert(
+        final_name.clone(),
+        crate::state::app_state::SignalInfo {
+            reader,
+            original_name: base_name,
+        },
+    );
+
+    Json(FileReadResponse {
+        id: "123".to_string(),
+        name: final_name.clone(),
+        path: request.base_url.clone(),
+        source: request.base_url,
+        headers: Some(vec![final_name]),
+        desc: None,
+        tags: None,
+        created_at: None,
+        source_url: None,
+    })
+    .into_response()
+}
+
 async fn ws_fetch(
     State(state): State<AppState>,
     Path(signal_name): Path<String>,
+    Query(query): Query<FetchQuery>,
     ws: WebSocketUpgrade,
 ) -> impl IntoResponse {
     let signal_info = {
@@ -177,9 +287,26 @@ async fn ws_fetch(
         }
     };
 
+    let range = FetchRange {
+        from: query.from,
+        to: query.to,
+    };
+
+    let protocol = match query.format.as_deref() {
+        Some("binary") => FetchProtocol::Binary,
+        _ => FetchProtocol::Json,
+    };
+
     // 🔒 reader will be locked inside the websocket handler
     ws.on_upgrade(move |socket| {
-        handle_ws_fetch(socket, signal_info.reader, signal_info.original_name)
+        handle_ws_fetch(
+            socket,
+            signal_info.reader,
+            signal_info.original_name,
+            range,
+            protocol,
+            query.points,
+        )
     })
 }
 
@@ -194,8 +321,9 @@ async fn list_readers(
     let mut groups: HashMap<usize, Vec<String>> = HashMap::new();
 
     for (_key, info) in signals.iter() {
-        // get raw pointer address for grouping
-        let ptr = Arc::as_ptr(&info.reader) as usize;
+        // get raw pointer address for grouping (narrow the fat dyn-trait
+        // pointer to its data address before the usize cast)
+        let ptr = Arc::as_ptr(&info.reader) as *const () as usize;
         groups.entry(ptr).or_default().push(info.original_name.clone());
     }
 
@@ -233,7 +361,7 @@ async fn reader_headers(
     // collect headers for matching pointer
     let mut headers: Vec<String> = Vec::new();
     for (_k, info) in signals.iter() {
-        if Arc::as_ptr(&info.reader) as usize == ptr {
+        if Arc::as_ptr(&info.reader) as *const () as usize == ptr {
             headers.push(info.original_name.clone());
         }
     }
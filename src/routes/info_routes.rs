@@ -10,13 +10,23 @@ use tokio::fs;
 use tracing::{debug, error};
 use serde::Serialize;
 
+use crate::state::app_state::AppState;
 
-pub fn health_routes() -> Router {
-    Router::new()
+pub fn health_routes(state: AppState) -> Router {
+    // `/` and `/health` stay open so liveness/readiness probes don't need a
+    // bearer token; `/info` and `/stop` are sensitive and go through auth.
+    let public = Router::new()
         .route("/", get(index_page))
-        .route("/health", get(health_check))
+        .route("/health", get(health_check));
+
+    let protected = Router::new()
         .route("/info", get(info_check))
         .route("/stop", get(stop_process))
+        .layer(axum::middleware::from_fn_with_state(state, crate::auth::auth_layer));
+
+    public
+        .merge(protected)
+        .layer(axum::middleware::from_fn(crate::routes::compression::compression_layer))
 }
 
 async fn index_page() -> Response {
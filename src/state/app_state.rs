@@ -2,11 +2,16 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::{RwLock, Mutex};
 
-use pltx_reader::PltxReader;
+use pltx_reader::source::{LocalSource, PltxSource};
+use pltx_reader::SignalBackend;
+
+use crate::auth::{ApiAuth, TokenAuth};
 
 #[derive(Clone)]
 pub struct SignalInfo {
-    pub reader: Arc<Mutex<PltxReader>>,
+    // Boxed so a signal group can be backed by a local `PltxReader` or by a
+    // federated `PltxRemoteReader` (see `crate::client::remote_reader`) alike.
+    pub reader: Arc<Mutex<dyn SignalBackend>>,
     pub original_name: String,  // The actual signal name in the file
 }
 
@@ -14,12 +19,23 @@ pub struct SignalInfo {
 pub struct AppState {
     // Maps unique_name -> SignalInfo (with reader and original name)
     pub signals: Arc<RwLock<HashMap<String, SignalInfo>>>,
+    // Maps scheme -> backend that can open it. Only "file" is registered by
+    // `AppState::new`; embedders that want `http://`/`s3://`/etc. paths to
+    // resolve must insert their own `PltxSource` impl for that scheme.
+    pub sources: Arc<HashMap<String, Arc<dyn PltxSource>>>,
+    // Authenticates incoming requests; defaults to the shared-token backend
+    pub auth: Arc<dyn ApiAuth>,
 }
 
 impl AppState {
     pub fn new() -> Self {
+        let mut sources: HashMap<String, Arc<dyn PltxSource>> = HashMap::new();
+        sources.insert("file".to_string(), Arc::new(LocalSource));
+
         Self {
             signals: Arc::new(RwLock::new(HashMap::new())),
+            sources: Arc::new(sources),
+            auth: Arc::new(TokenAuth),
         }
     }
 }
@@ -28,4 +44,4 @@ impl Default for AppState {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}